@@ -14,8 +14,9 @@ BASE_DIR = Path(__file__).resolve().parent.parent
 
 # Security settings
 SECRET_KEY = os.getenv('SECRET_KEY', 'django-insecure-dev-key-change-in-production')
-DEBUG = os.getenv('DEBUG', 'True') == 'True'
-ALLOWED_HOSTS = os.getenv('ALLOWED_HOSTS', 'localhost,127.0.0.1').split(',')
+# DJANGO_DEBUG is set by the desktop shell and takes precedence over DEBUG
+DEBUG = os.getenv('DJANGO_DEBUG', os.getenv('DEBUG', 'True')) == 'True'
+ALLOWED_HOSTS = os.getenv('ALLOWED_HOSTS', 'localhost,127.0.0.1,[::1]').split(',')
 
 # Import patches early to suppress BrokenPipeError
 try:
@@ -89,6 +90,12 @@ DATABASES = {
     }
 }
 
+# Read-only mode (for viewing backups): open SQLite with mode=ro so nothing can be written
+# Django's SQLite backend connects with uri=True, so a file: URI is accepted as NAME
+if os.getenv('DATABASE_READ_ONLY') == '1':
+    DATABASES['default']['NAME'] = f'{Path(db_name).resolve().as_uri()}?mode=ro'
+
+
 # Password validation
 AUTH_PASSWORD_VALIDATORS = [
     {
@@ -50,12 +50,19 @@ def main():
     parser.add_argument('--port', type=int, default=8000, help='Port to bind to')
     parser.add_argument('--database-path', help='Path to SQLite database file')
     parser.add_argument('--migrate', action='store_true', help='Run migrations before starting server')
+    parser.add_argument('--manage', nargs=argparse.REMAINDER,
+                        help='Run a management command (e.g. --manage showmigrations --plan) and exit')
     args, unknown = parser.parse_known_args()
     
     # Set database path if provided
     if args.database_path:
         os.environ['DATABASE_PATH'] = args.database_path
     
+    # Run a single management command without starting the server
+    if args.manage:
+        execute_from_command_line(['manage.py', *args.manage])
+        return
+    
     # Run migrations if requested
     if args.migrate:
         print("Running database migrations...")
@@ -63,10 +70,12 @@ def main():
         execute_from_command_line(migrate_args)
     
     # Start the server
+    # IPv6 addresses (e.g. ::1) must be bracketed for runserver's addr:port syntax
+    host = f'[{args.host}]' if ':' in args.host else args.host
     server_args = [
         'manage.py',
         'runserver',
-        f'{args.host}:{args.port}',
+        f'{host}:{args.port}',
     ]
     # Disable autoreload when running as bundled executable (PyInstaller)
     # Autoreload causes issues with PyInstaller as it tries to restart with arguments
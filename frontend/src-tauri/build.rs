@@ -1,3 +1,14 @@
 fn main() {
+    // Bundle the backend's requirements.txt so a stripped source tree can still install its
+    // dependencies (see `restore_bundled_requirements`)
+    let requirements = std::path::Path::new("../../backend/requirements.txt");
+    println!("cargo:rerun-if-changed={}", requirements.display());
+    if requirements.exists() {
+        let _ = std::fs::create_dir_all("resources");
+        if let Err(e) = std::fs::copy(requirements, "resources/requirements.txt") {
+            println!("cargo:warning=Could not bundle requirements.txt: {}", e);
+        }
+    }
+
     tauri_build::build()
 }
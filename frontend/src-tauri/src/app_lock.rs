@@ -0,0 +1,45 @@
+use std::fs::{File, OpenOptions, TryLockError};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How long to wait for another instance to release the app data lock
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Run `write` while holding an exclusive advisory lock on `<app_data_dir>/.lock`
+/// Guards files in the app data directory against two app instances writing them at once.
+/// The lock is released when the closure returns (or the process exits)
+pub fn with_lock<T>(app_data_dir: &Path, write: impl FnOnce() -> T) -> Result<T, String> {
+    std::fs::create_dir_all(app_data_dir)
+        .map_err(|e| format!("Could not create {:?}: {}", app_data_dir, e))?;
+    let lock_path = app_data_dir.join(".lock");
+    let file = OpenOptions::new()
+        .create(true)
+        .truncate(false)
+        .write(true)
+        .open(&lock_path)
+        .map_err(|e| format!("Could not open lock file {:?}: {}", lock_path, e))?;
+
+    acquire(&file)?;
+    let result = write();
+    let _ = file.unlock();
+    Ok(result)
+}
+
+/// Poll for the lock so a stuck instance can't block this one forever
+fn acquire(file: &File) -> Result<(), String> {
+    let deadline = Instant::now() + LOCK_TIMEOUT;
+    loop {
+        match file.try_lock() {
+            Ok(()) => return Ok(()),
+            Err(TryLockError::WouldBlock) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(50));
+            }
+            Err(TryLockError::WouldBlock) => {
+                return Err("App data directory is locked by another instance".to_string());
+            }
+            Err(TryLockError::Error(e)) => {
+                return Err(format!("Could not lock app data directory: {}", e));
+            }
+        }
+    }
+}
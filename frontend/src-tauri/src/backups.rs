@@ -0,0 +1,336 @@
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use log::{debug, info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+/// How often the scheduler wakes up to check whether a backup is due
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+/// A database backup file in the backups directory
+#[derive(Debug, Clone, Serialize)]
+pub struct BackupInfo {
+    pub path: PathBuf,
+    /// Modification time in seconds since the Unix epoch
+    pub timestamp: u64,
+    pub size: u64,
+}
+
+/// Directory holding database backups: `<app_data_dir>/backups`
+pub fn backups_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    crate::storage::app_data_dir(app).map(|dir| dir.join("backups"))
+}
+
+/// Backups in the backups directory, newest first
+pub fn list(app: &tauri::AppHandle) -> Result<Vec<BackupInfo>, String> {
+    let dir = backups_dir(app)?;
+    let entries = match std::fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(format!("Could not read backups directory {:?}: {}", dir, e)),
+    };
+
+    let mut backups: Vec<BackupInfo> = entries
+        .flatten()
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            if !metadata.is_file() {
+                return None;
+            }
+            let timestamp = metadata
+                .modified()
+                .ok()
+                .and_then(|time| time.duration_since(UNIX_EPOCH).ok())
+                .map(|duration| duration.as_secs())
+                .unwrap_or(0);
+            Some(BackupInfo {
+                path: entry.path(),
+                timestamp,
+                size: metadata.len(),
+            })
+        })
+        .collect();
+    backups.sort_by_key(|backup| std::cmp::Reverse(backup.timestamp));
+    Ok(backups)
+}
+
+/// Delete a single backup
+/// The path must resolve to a file directly inside the backups directory, so this cannot be
+/// used to delete arbitrary files
+pub fn delete(app: &tauri::AppHandle, path: &Path) -> Result<(), String> {
+    let dir = backups_dir(app)?
+        .canonicalize()
+        .map_err(|e| format!("Backups directory is not available: {}", e))?;
+    let target = path
+        .canonicalize()
+        .map_err(|e| format!("Backup {:?} not found: {}", path, e))?;
+
+    if target.parent() != Some(dir.as_path()) || !target.is_file() {
+        warn!("Refusing to delete {:?}: not a backup in {:?}", target, dir);
+        return Err(format!("{:?} is not a backup file", path));
+    }
+
+    std::fs::remove_file(&target).map_err(|e| format!("Failed to delete backup: {}", e))?;
+    info!("Deleted backup {:?}", target);
+    Ok(())
+}
+
+/// Database file stem a backup was made from, parsed from `<db name>-<reason>-<unix time>.sqlite3[.gz]`
+fn source_stem(backup: &Path) -> Option<&str> {
+    let name = backup.file_name()?.to_str()?;
+    let name = name.strip_suffix(".gz").unwrap_or(name);
+    let mut parts = name.strip_suffix(".sqlite3")?.rsplitn(3, '-');
+    let (_timestamp, _reason) = (parts.next()?, parts.next()?);
+    parts.next()
+}
+
+/// Delete every backup made from the database with this file stem; returns how many were removed
+pub fn delete_for_database(app: &tauri::AppHandle, stem: &str) -> Result<usize, String> {
+    let mut removed = 0;
+    for backup in list(app)? {
+        if source_stem(&backup.path) == Some(stem) {
+            std::fs::remove_file(&backup.path)
+                .map_err(|e| format!("Failed to delete backup {:?}: {}", backup.path, e))?;
+            removed += 1;
+        }
+    }
+    Ok(removed)
+}
+
+/// Copy the database into the backups directory as `<db name>-<reason>-<unix time>.sqlite3`
+/// `VACUUM INTO` produces a consistent snapshot even while the backend is writing
+pub fn create(app: &tauri::AppHandle, db_path: &Path, reason: &str) -> Result<BackupInfo, String> {
+    let dir = backups_dir(app)?;
+    std::fs::create_dir_all(&dir)
+        .map_err(|e| format!("Could not create backups directory {:?}: {}", dir, e))?;
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    let stem = db_path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().to_string())
+        .unwrap_or_else(|| "db".to_string());
+    let path = dir.join(format!("{}-{}-{}.sqlite3", stem, reason, timestamp));
+
+    let connection =
+        rusqlite::Connection::open_with_flags(db_path, rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| format!("Could not open database {:?}: {}", db_path, e))?;
+    connection
+        .execute("VACUUM INTO ?1", [path.to_string_lossy()])
+        .map_err(|e| format!("Backup of {:?} failed: {}", db_path, e))?;
+
+    let size = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+    info!("Created backup {:?} ({} bytes)", path, size);
+    Ok(BackupInfo {
+        path,
+        timestamp,
+        size,
+    })
+}
+
+/// Delete the oldest backups so at most `max_backups` remain
+pub fn prune(app: &tauri::AppHandle, max_backups: usize) -> Result<(), String> {
+    for backup in list(app)?.into_iter().skip(max_backups) {
+        match std::fs::remove_file(&backup.path) {
+            Ok(()) => info!("Pruned old backup {:?}", backup.path),
+            Err(e) => warn!("Failed to prune backup {:?}: {}", backup.path, e),
+        }
+    }
+    Ok(())
+}
+
+/// Gzip uncompressed backups older than `after_days` into `<name>.gz`
+/// The compressed file keeps the original modification time so listing and pruning order is unchanged
+pub fn compress_old(app: &tauri::AppHandle, after_days: u64) -> Result<(), String> {
+    let threshold_secs = after_days * 24 * 3600;
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+
+    for backup in list(app)? {
+        if is_compressed(&backup.path) || now.saturating_sub(backup.timestamp) < threshold_secs {
+            continue;
+        }
+        match compress_file(&backup.path) {
+            Ok(compressed) => info!("Compressed old backup to {:?}", compressed),
+            Err(e) => warn!("Failed to compress backup {:?}: {}", backup.path, e),
+        }
+    }
+    Ok(())
+}
+
+/// Whether a file was gzipped by `compress_file`
+pub fn is_compressed(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "gz")
+}
+
+/// Contents of a file, decompressed when it is gzipped
+pub fn read_decompressed(path: &Path) -> std::io::Result<Vec<u8>> {
+    let mut contents = Vec::new();
+    if is_compressed(path) {
+        std::io::Read::read_to_end(
+            &mut GzDecoder::new(std::fs::File::open(path)?),
+            &mut contents,
+        )?;
+    } else {
+        contents = std::fs::read(path)?;
+    }
+    Ok(contents)
+}
+
+/// A path SQLite can open for `path`: the file itself, or for a gzipped backup a decompressed
+/// copy in the temp directory (reused while it is newer than the backup)
+pub fn sqlite_readable(path: &Path) -> Result<PathBuf, String> {
+    if !is_compressed(path) {
+        return Ok(path.to_path_buf());
+    }
+    let name = path
+        .file_stem()
+        .ok_or_else(|| format!("{:?} has no file name", path))?;
+    let dir = std::env::temp_dir().join("budget-planer-backups");
+    std::fs::create_dir_all(&dir).map_err(|e| format!("Could not create {:?}: {}", dir, e))?;
+    let target = dir.join(name);
+
+    let modified = |path: &Path| std::fs::metadata(path).and_then(|m| m.modified()).ok();
+    if modified(&target).is_some() && modified(&target) >= modified(path) {
+        return Ok(target);
+    }
+    let result = std::fs::File::open(path).and_then(|input| {
+        let mut output = std::fs::File::create(&target)?;
+        std::io::copy(&mut GzDecoder::new(input), &mut output).map(|_| ())
+    });
+    if let Err(e) = result {
+        let _ = std::fs::remove_file(&target);
+        return Err(format!("Could not decompress {:?}: {}", path, e));
+    }
+    info!("Decompressed backup {:?} to {:?}", path, target);
+    Ok(target)
+}
+
+/// Replace a file with a gzipped copy next to it
+pub fn compress_file(path: &Path) -> std::io::Result<PathBuf> {
+    let mut compressed_name = path.as_os_str().to_owned();
+    compressed_name.push(".gz");
+    let compressed = PathBuf::from(compressed_name);
+
+    let modified = std::fs::metadata(path)?.modified()?;
+    let mut input = std::fs::File::open(path)?;
+    let output = std::fs::File::create(&compressed)?;
+    let mut encoder = GzEncoder::new(output, Compression::default());
+    let result = std::io::copy(&mut input, &mut encoder).and_then(|_| encoder.finish());
+    let output = match result {
+        Ok(output) => output,
+        Err(e) => {
+            let _ = std::fs::remove_file(&compressed);
+            return Err(e);
+        }
+    };
+    output.set_modified(modified)?;
+    drop(input);
+    std::fs::remove_file(path)?;
+    Ok(compressed)
+}
+
+/// Modification time and size of a file, `None` when it doesn't exist
+type FileSignature = Option<(SystemTime, u64)>;
+
+fn file_signature(path: &Path) -> FileSignature {
+    let metadata = std::fs::metadata(path).ok()?;
+    Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Modification time and size of the database and its `-wal` file, used to skip backups of an
+/// unchanged database; in WAL mode committed writes stay in `-wal` until a checkpoint
+fn db_signature(db_path: &Path) -> Option<(PathBuf, SystemTime, u64, FileSignature)> {
+    let (modified, len) = file_signature(db_path)?;
+    let mut wal_path = db_path.as_os_str().to_owned();
+    wal_path.push("-wal");
+    Some((
+        db_path.to_path_buf(),
+        modified,
+        len,
+        file_signature(Path::new(&wal_path)),
+    ))
+}
+
+/// Start the background thread for automatic backups
+/// The interval is re-read from the config on every tick, so changes apply without a restart
+pub fn start_scheduler(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_backup = Instant::now();
+        let mut last_signature = None;
+        loop {
+            std::thread::sleep(SCHEDULER_TICK);
+
+            crate::logging::compress_rotated_logs(&app);
+            let config = crate::config::current(&app);
+            if config.compress_backups_after_days > 0
+                && let Err(e) = compress_old(&app, config.compress_backups_after_days)
+            {
+                warn!("Failed to compress old backups: {}", e);
+            }
+            if config.auto_backup_interval_hours == 0 {
+                continue;
+            }
+            let interval = Duration::from_secs(config.auto_backup_interval_hours * 3600);
+            if last_backup.elapsed() < interval {
+                continue;
+            }
+            last_backup = Instant::now();
+
+            // A clean session's throwaway database is not worth keeping, and while a backup is
+            // opened read-only, backing it up and pruning would only churn the backups directory
+            if crate::clean_session::is_active(&app) || config.read_only {
+                continue;
+            }
+            let Some(db_path) = crate::active_db_path(&app) else {
+                continue;
+            };
+            let signature = db_signature(&db_path);
+            if signature.is_some() && signature == last_signature {
+                debug!("Database unchanged since the last backup, skipping");
+                continue;
+            }
+
+            match create(&app, &db_path, "auto") {
+                Ok(backup) => {
+                    last_signature = signature;
+                    if let Err(e) = prune(&app, config.max_backups) {
+                        warn!("Failed to prune backups: {}", e);
+                    }
+                    crate::events::emit(&app, "backend://backup_created", backup);
+                }
+                Err(e) => warn!("Automatic backup failed: {}", e),
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_files_read_back_unchanged() {
+        let dir =
+            std::env::temp_dir().join(format!("budget-planer-test-{}-gz", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("db-auto-1.sqlite3");
+        let contents = b"SQLite format 3\0 and some pages".repeat(100);
+        std::fs::write(&path, &contents).unwrap();
+
+        let compressed = compress_file(&path).unwrap();
+        let read_back = read_decompressed(&compressed).unwrap();
+        let readable = std::fs::read(sqlite_readable(&compressed).unwrap()).unwrap();
+
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(!path.exists());
+        assert_eq!(read_back, contents);
+        assert_eq!(readable, contents);
+    }
+}
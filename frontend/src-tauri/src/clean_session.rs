@@ -0,0 +1,84 @@
+use log::{info, warn};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+/// Databases of a running clean session: the user's real one and the throwaway one in use
+struct Session {
+    previous: PathBuf,
+    temp: PathBuf,
+}
+
+/// Clean session in progress, if any
+#[derive(Default)]
+pub struct CleanSession(Mutex<Option<Session>>);
+
+fn take_session(app: &tauri::AppHandle) -> Option<Session> {
+    app.try_state::<CleanSession>()
+        .and_then(|state| state.0.lock().ok().and_then(|mut session| session.take()))
+}
+
+fn set_session(app: &tauri::AppHandle, session: Session) {
+    if let Some(state) = app.try_state::<CleanSession>()
+        && let Ok(mut current) = state.0.lock()
+    {
+        *current = Some(session);
+    }
+}
+
+/// Whether a clean session is running
+pub fn is_active(app: &tauri::AppHandle) -> bool {
+    app.try_state::<CleanSession>()
+        .and_then(|state| state.0.lock().ok().map(|session| session.is_some()))
+        .unwrap_or(false)
+}
+
+/// Restart the backend against a fresh, migrated database in the temp directory
+/// The real database is left untouched until `end` switches back to it
+pub fn start(app: &tauri::AppHandle) -> Result<(), String> {
+    if is_active(app) {
+        return Err("A clean session is already running".to_string());
+    }
+    let previous = crate::active_db_path(app).ok_or("Active database path is not known")?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis())
+        .unwrap_or(0);
+    let temp = std::env::temp_dir().join(format!("budget-planer-clean-{}.sqlite3", stamp));
+
+    info!("Starting clean session on {:?}", temp);
+    crate::stop_backend(app);
+    crate::set_active_db_path(app, temp.clone());
+    let result = crate::migrations::apply(app).and_then(|_| crate::restart_backend(app));
+    if let Err(e) = result {
+        warn!(
+            "Clean session failed to start: {}, restoring {:?}",
+            e, previous
+        );
+        crate::set_active_db_path(app, previous);
+        let _ = std::fs::remove_file(&temp);
+        if let Err(restore_err) = crate::restart_backend(app) {
+            warn!(
+                "Failed to restart backend on the real database: {}",
+                restore_err
+            );
+        }
+        return Err(e);
+    }
+    set_session(app, Session { previous, temp });
+    Ok(())
+}
+
+/// Go back to the real database and delete the clean session's database
+pub fn end(app: &tauri::AppHandle) -> Result<(), String> {
+    let Session { previous, temp } = take_session(app).ok_or("No clean session is running")?;
+    info!("Ending clean session, restoring {:?}", previous);
+    crate::stop_backend(app);
+    crate::set_active_db_path(app, previous);
+    let result = crate::restart_backend(app);
+    if let Err(e) = std::fs::remove_file(&temp) {
+        warn!("Could not delete clean session database {:?}: {}", temp, e);
+    }
+    result
+}
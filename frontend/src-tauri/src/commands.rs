@@ -0,0 +1,661 @@
+use crate::port::{self, PortConflictAction};
+use crate::timing::{PhaseTiming, StartupTimeline};
+
+/// Return each phase and its duration from the most recent backend startup
+#[tauri::command]
+pub fn get_startup_timeline(timeline: tauri::State<'_, StartupTimeline>) -> Vec<PhaseTiming> {
+    timeline.snapshot()
+}
+
+/// Return a Markdown environment summary for pasting into bug reports
+#[tauri::command]
+pub fn get_env_report(app: tauri::AppHandle) -> String {
+    crate::diagnostics::build_env_report(&app)
+}
+
+/// Enable or disable Django DEBUG, persist the choice and restart the backend to apply it
+#[tauri::command]
+pub async fn set_debug(app: tauri::AppHandle, enabled: bool) -> Result<(), String> {
+    crate::config::update(&app, |config| config.debug = enabled)?;
+    log::info!("Django DEBUG set to {}, restarting backend", enabled);
+    tauri::async_runtime::spawn_blocking(move || crate::restart_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Enter or leave read-only mode and restart the backend; no migrations or writes touch the database
+/// A `path` (e.g. a backup) is opened instead of the active profile's database until the next switch
+#[tauri::command]
+pub async fn set_read_only(
+    app: tauri::AppHandle,
+    enabled: bool,
+    path: Option<String>,
+) -> Result<(), String> {
+    let path = path.map(std::path::PathBuf::from);
+    if let Some(path) = &path
+        && !path.is_file()
+    {
+        return Err(format!("Database {:?} does not exist", path));
+    }
+    // A gzipped backup is viewed through a decompressed copy
+    let path = path
+        .map(|path| crate::backups::sqlite_readable(&path))
+        .transpose()?;
+    let config = crate::config::update(&app, |config| config.read_only = enabled)?;
+    match path {
+        Some(path) => crate::set_active_db_path(&app, path),
+        // Leaving read-only mode goes back to the profile's database, never a viewed backup
+        None if !enabled => {
+            let app_data_dir = crate::storage::app_data_dir(&app)?;
+            crate::set_active_db_path(
+                &app,
+                crate::profiles::resolve_db_path(&app_data_dir, &config),
+            );
+        }
+        None => {}
+    }
+    log::info!("Read-only mode set to {}, restarting backend", enabled);
+    tauri::async_runtime::spawn_blocking(move || crate::restart_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Choose between the bundled executable, the Python source tree or automatic selection,
+/// persist the choice and restart the backend in that mode
+#[tauri::command]
+pub async fn set_backend_mode(
+    app: tauri::AppHandle,
+    mode: crate::config::LaunchMode,
+) -> Result<(), String> {
+    crate::config::update(&app, |config| config.backend_mode = mode)?;
+    log::info!("Backend mode set to {:?}, restarting backend", mode);
+    tauri::async_runtime::spawn_blocking(move || crate::restart_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Return the port the backend is started on
+#[tauri::command]
+pub fn get_backend_port(app: tauri::AppHandle) -> u16 {
+    crate::port::current_port(&app)
+}
+
+/// Return the URL scheme the backend is served with ("http" or "https")
+#[tauri::command]
+pub fn get_backend_scheme(app: tauri::AppHandle) -> &'static str {
+    crate::backend_scheme(&app)
+}
+
+/// Persist the port the backend is started on and restart it there
+/// Fails without changing anything if the port is out of range or can't be bound
+#[tauri::command]
+pub async fn set_backend_port(app: tauri::AppHandle, port: u16) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        if port < port::MIN_BACKEND_PORT {
+            return Err(format!(
+                "Port {} is out of range ({}-{})",
+                port,
+                port::MIN_BACKEND_PORT,
+                u16::MAX
+            ));
+        }
+        // The running backend holds its own port, so only a different one has to be free
+        if port != port::current_port(&app) && !port::is_port_free(port) {
+            return Err(format!("Port {} is already in use", port));
+        }
+        crate::config::update(&app, |config| config.backend_port = port)?;
+        log::info!("Backend port set to {}, restarting backend", port);
+        port::set_current_port(&app, port);
+        crate::events::emit(&app, "backend://port_changed", port);
+        crate::restart_backend(&app)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Resolve a `backend://port_conflict` on `port` (the conflicting port from the event) by
+/// killing the other application there and moving back, or by staying on a free port
+/// Returns the port the backend runs on afterwards
+#[tauri::command]
+pub async fn resolve_port_conflict(
+    app: tauri::AppHandle,
+    port: u16,
+    action: PortConflictAction,
+) -> Result<u16, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        match action {
+            PortConflictAction::Kill => {
+                if !crate::config::current(&app).manage_port {
+                    return Err(
+                        "Killing processes on the port is disabled (manage_port = false)"
+                            .to_string(),
+                    );
+                }
+                // Our own backend may be on the port by now; only the other application goes
+                for owner in port::identify_port_owners(port)
+                    .into_iter()
+                    .filter(|owner| !owner.is_backend)
+                {
+                    log::warn!(
+                        "Killing {} (PID {}) on port {} at the user's request",
+                        owner.name,
+                        owner.pid,
+                        port
+                    );
+                    port::kill_pid(owner.pid);
+                }
+                if !port::wait_until_free(port, port::PORT_RELEASE_TIMEOUT) {
+                    return Err(format!("Port {} is still in use", port));
+                }
+                if port != port::current_port(&app) {
+                    port::set_current_port(&app, port);
+                    crate::events::emit(&app, "backend://port_changed", port);
+                    crate::restart_backend(&app)?;
+                }
+            }
+            PortConflictAction::UseOtherPort => {
+                // Startup already moved off the conflicting port unless it is still the current one
+                if port == port::current_port(&app) {
+                    port::move_to_next_free_port(&app, port)?;
+                    crate::restart_backend(&app)?;
+                }
+            }
+        }
+        Ok(port::current_port(&app))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Kill orphaned backend processes holding the backend port and report whether it is free now
+/// A manual recovery lever for a backend left behind by a crash; unrelated processes are not killed
+#[tauri::command]
+pub async fn clear_backend_port(app: tauri::AppHandle) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        if !crate::config::current(&app).manage_port {
+            return Err(
+                "Killing processes on the port is disabled (manage_port = false)".to_string(),
+            );
+        }
+        // Our own backend answers on the port; it is stopped or restarted, not cleared away
+        if crate::backend_alive(&app) {
+            return Err("The backend is running; restart it instead".to_string());
+        }
+        let port = port::current_port(&app);
+        log::info!("Clearing port {} at the user's request", port);
+        port::kill_process_on_port(port).map_err(|e| e.to_string())
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Send backend output and setup progress events only to the window with this label (e.g. a
+/// splash screen) instead of every window; `None` broadcasts them again
+#[tauri::command]
+pub fn set_progress_window(app: tauri::AppHandle, label: Option<String>) -> Result<(), String> {
+    crate::events::set_progress_window(&app, label)
+}
+
+/// Change the active log level without restarting (e.g. "debug" while reproducing an issue)
+#[tauri::command]
+pub fn set_log_level(level: String) -> Result<(), String> {
+    let level = crate::logging::parse_level(&level)?;
+    crate::logging::set_level(level);
+    Ok(())
+}
+
+/// List the profiles with their database size and which one is active
+#[tauri::command]
+pub fn list_profiles(app: tauri::AppHandle) -> Result<Vec<crate::profiles::ProfileInfo>, String> {
+    crate::profiles::list(&app)
+}
+
+/// Delete a profile's database, its WAL/SHM files and its backups; the active profile is refused
+#[tauri::command]
+pub fn delete_profile(app: tauri::AppHandle, name: String) -> Result<(), String> {
+    crate::profiles::delete(&app, &name)
+}
+
+/// Switch to another profile (database), remember it for the next launch and restart the backend
+#[tauri::command]
+pub async fn switch_profile(app: tauri::AppHandle, name: String) -> Result<(), String> {
+    crate::profiles::validate_profile_name(&name)?;
+    let app_data_dir = crate::storage::app_data_dir(&app)?;
+    let db_path = crate::profiles::profile_db_path(&app_data_dir, &name);
+
+    crate::set_active_db_path(&app, db_path);
+    crate::config::update(&app, |config| config.active_profile = name.clone())?;
+    log::info!("Switched to profile '{}', restarting backend", name);
+
+    tauri::async_runtime::spawn_blocking(move || crate::restart_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Report whether the active database has unapplied migrations, without applying them
+#[tauri::command]
+pub async fn has_pending_migrations(app: tauri::AppHandle) -> Result<bool, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::migrations::pending(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Apply pending migrations, e.g. after the user confirmed a `backend://migrations_pending` prompt
+#[tauri::command]
+pub async fn run_migrations(app: tauri::AppHandle) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::migrations::apply(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Restart the backend against an arbitrary database file, e.g. a throwaway db for testing
+/// With `migrate` the database is migrated before the backend starts so migration errors are reported here
+/// The choice is not persisted; on failure the backend is restarted against the previous database
+#[tauri::command]
+pub async fn use_database(
+    app: tauri::AppHandle,
+    path: String,
+    migrate: bool,
+) -> Result<(), String> {
+    let db_path = std::path::PathBuf::from(&path);
+    if !db_path.is_absolute() {
+        return Err(format!("Database path must be absolute: {}", path));
+    }
+    let previous = crate::active_db_path(&app).ok_or("Active database path is not known")?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        // Stop, swap and start run as one sequence, so a concurrent restart or the watchdog
+        // can't launch a backend in between or have its backend killed by this one
+        let result = crate::run_exclusive(&app, || {
+            log::info!("Switching backend to database {:?}", db_path);
+            crate::restarts::record(&app, crate::restarts::RestartReason::Manual);
+            crate::stop_backend(&app);
+            crate::set_active_db_path(&app, db_path.clone());
+
+            let result = if migrate {
+                crate::migrations::apply(&app).map(|_| ())
+            } else {
+                Ok(())
+            }
+            .and_then(|_| crate::restart_now(&app));
+
+            if let Err(e) = &result {
+                log::warn!(
+                    "Could not use database: {}, restoring previous database {:?}",
+                    e,
+                    previous
+                );
+                crate::set_active_db_path(&app, previous.clone());
+                if let Err(restore_err) = crate::restart_now(&app) {
+                    log::error!(
+                        "Failed to restart backend on previous database: {}",
+                        restore_err
+                    );
+                }
+            }
+            result
+        });
+
+        match result {
+            // Another start/stop sequence is running; the restart queued behind it picks up the
+            // new path (migrations then run in the background as on any startup)
+            Err(e) if e == crate::RESTART_IN_PROGRESS => {
+                log::info!(
+                    "Backend restart in progress, switching to {:?} once it finishes",
+                    db_path
+                );
+                crate::set_active_db_path(&app, db_path);
+                Ok(())
+            }
+            result => result,
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Backups in `<app_data_dir>/backups`, newest first
+#[tauri::command]
+pub fn list_backups(app: tauri::AppHandle) -> Result<Vec<crate::backups::BackupInfo>, String> {
+    crate::backups::list(&app)
+}
+
+/// Check a database file (header, integrity, latest migration) before restoring or switching to it
+#[tauri::command]
+pub async fn inspect_database(path: String) -> Result<crate::database::DatabaseInspection, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let path = std::path::PathBuf::from(path);
+        // A gzipped backup is checked through a decompressed copy but reported under its own path
+        let mut inspection = crate::database::inspect(&crate::backups::sqlite_readable(&path)?);
+        inspection.path = path;
+        Ok(inspection)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Applied migrations of the active database with their timestamps, for support requests
+#[tauri::command]
+pub async fn get_migration_history(
+    app: tauri::AppHandle,
+) -> Result<Vec<crate::database::MigrationEntry>, String> {
+    let db_path = crate::active_db_path(&app).ok_or("No active database")?;
+    tauri::async_runtime::spawn_blocking(move || crate::database::migration_history(&db_path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Disk space used by the database, its WAL/SHM files, backups, logs and the app data directory
+#[tauri::command]
+pub async fn get_storage_usage(
+    app: tauri::AppHandle,
+) -> Result<crate::storage::StorageUsage, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::storage::usage(&app))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Delete one backup; the path must point into the backups directory
+#[tauri::command]
+pub fn delete_backup(app: tauri::AppHandle, path: String) -> Result<(), String> {
+    crate::backups::delete(&app, std::path::Path::new(&path))
+}
+
+/// Checkpoint the active database's write-ahead log and truncate the `-wal` file
+#[tauri::command]
+pub async fn wal_checkpoint(app: tauri::AppHandle) -> Result<(), String> {
+    if crate::config::current(&app).read_only {
+        return Err("The database is open read-only".to_string());
+    }
+    let db_path = crate::active_db_path(&app).ok_or("No active database")?;
+    tauri::async_runtime::spawn_blocking(move || crate::wal::checkpoint(&db_path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Hit the health endpoint once and return the round-trip latency in milliseconds
+#[tauri::command]
+pub async fn ping_backend(app: tauri::AppHandle) -> Result<u64, String> {
+    let url = crate::health_url(&app, port::current_port(&app));
+    tauri::async_runtime::spawn_blocking(move || {
+        let client = crate::health_client::shared(&app)?;
+        crate::probe_health(&app, &client, &url, &crate::config::current(&app))
+            .map_err(|reason| format!("Backend health check failed: {}", reason))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Quit the app after the backend has been stopped and its port freed
+/// Unlike closing the window, cleanup finishes before the process exits
+#[tauri::command]
+pub async fn quit_app(app: tauri::AppHandle) -> Result<(), String> {
+    log::info!("Quit requested from the frontend, shutting down backend...");
+    let app_for_shutdown = app.clone();
+    tauri::async_runtime::spawn_blocking(move || crate::shutdown_backend(&app_for_shutdown))
+        .await
+        .map_err(|e| e.to_string())?;
+    app.exit(0);
+    Ok(())
+}
+
+/// Return the configuration in force: the config file merged over the defaults
+/// The config holds no secrets, so nothing needs redacting
+#[tauri::command]
+pub fn get_effective_config(app: tauri::AppHandle) -> crate::config::Config {
+    crate::config::current(&app)
+}
+
+/// Re-read `config.toml` without restarting the app
+/// Settings that need a backend restart are listed in `pending_restart`
+#[tauri::command]
+pub fn reload_config(app: tauri::AppHandle) -> Result<crate::config::ReloadedConfig, String> {
+    crate::config::reload(&app)
+}
+
+/// Run an allowlisted Django management command (e.g. `check`) and return its output
+#[tauri::command]
+pub async fn run_management_command(
+    app: tauri::AppHandle,
+    name: String,
+    args: Vec<String>,
+) -> Result<String, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::management::run_allowed(&app, &name, &args))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Return the last `lines` lines of the app (Rust-side) log file for attaching to issues
+#[tauri::command]
+pub fn get_app_logs(app: tauri::AppHandle, lines: usize) -> Result<Vec<String>, String> {
+    crate::logging::tail_app_log(&app, lines)
+}
+
+/// Revert to the settings of the last startup that passed a health check and restart the backend
+/// A recovery path when a settings change left the backend unable to start
+#[tauri::command]
+pub async fn restore_last_good_config(
+    app: tauri::AppHandle,
+) -> Result<crate::known_good::KnownGood, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::known_good::restore(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Return the command line of the last backend launch, to run by hand when reproducing a problem
+#[tauri::command]
+pub fn get_backend_command(app: tauri::AppHandle) -> Result<String, String> {
+    crate::diagnostics::startup_info(&app)
+        .backend_command
+        .ok_or_else(|| "The backend has not been started yet".to_string())
+}
+
+/// Tell a real backend executable apart from a placeholder left by a broken build, or none at all
+#[tauri::command]
+pub async fn backend_binary_status(
+    app: tauri::AppHandle,
+) -> Result<crate::diagnostics::BinaryStatus, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::backend_binary_status(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Return the paths probed for the backend executable during the last startup and which one was used
+#[tauri::command]
+pub fn get_discovery_report(app: tauri::AppHandle) -> crate::diagnostics::DiscoveryReport {
+    crate::diagnostics::startup_info(&app).discovery
+}
+
+/// Scan for the backend executable again (e.g. after moving it) and return the fresh report
+/// The result replaces the report from the last startup returned by `get_discovery_report`
+#[tauri::command]
+pub async fn rediscover_backend(
+    app: tauri::AppHandle,
+) -> Result<crate::diagnostics::DiscoveryReport, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::rediscover_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Re-check backend health (e.g. after the system resumed) and restart it if it is unresponsive
+#[tauri::command]
+pub async fn reconnect_backend(app: tauri::AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || crate::reconnect_backend(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Zip the app log, effective config and diagnostics into `dest` for attaching to bug reports
+#[tauri::command]
+pub async fn export_support_bundle(app: tauri::AppHandle, dest: String) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::support::export_bundle(&app, std::path::Path::new(&dest))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Report whether Python, the venv, Django and the bundled backend are in place, without launching
+#[tauri::command]
+pub async fn check_environment(
+    app: tauri::AppHandle,
+) -> Result<crate::diagnostics::EnvReadiness, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::diagnostics::check_environment(&app))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Recent CPU usage of the backend in percent of one core, to tell a busy backend from a slow UI
+#[tauri::command]
+pub async fn get_backend_cpu(app: tauri::AppHandle) -> Result<f32, String> {
+    let pid = crate::backend_pid(&app).ok_or("The backend is not running")?;
+    tauri::async_runtime::spawn_blocking(move || crate::diagnostics::backend_cpu_usage(pid))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// PID, spawn time and uptime of the managed backend, to confirm a restart replaced the process
+#[tauri::command]
+pub fn get_backend_process_info(app: tauri::AppHandle) -> Option<crate::diagnostics::ProcessInfo> {
+    crate::diagnostics::process_info(&app)
+}
+
+/// How often the backend was restarted this session, by reason, for a stability indicator
+#[tauri::command]
+pub fn get_restart_count(app: tauri::AppHandle) -> crate::restarts::RestartStats {
+    crate::restarts::stats(&app)
+}
+
+/// Connectivity self-test: the backend's port, the port the frontend uses and whether the
+/// backend answers there, so a frontend still pointing at the default port shows up plainly
+#[tauri::command]
+pub async fn verify_connection(
+    app: tauri::AppHandle,
+    frontend_port: Option<u16>,
+) -> Result<crate::diagnostics::ConnectionReport, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::diagnostics::verify_connection(&app, frontend_port)
+    })
+    .await
+    .map_err(|e| e.to_string())
+}
+
+/// Time `iterations` sequential health requests and return min/max/average/p95 latency
+#[tauri::command]
+pub async fn benchmark_backend(
+    app: tauri::AppHandle,
+    iterations: u32,
+) -> Result<crate::diagnostics::BenchmarkResult, String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::diagnostics::benchmark_backend(&app, iterations)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+/// Timestamp, latency and outcome of the most recent health probe, for a "last seen healthy" indicator
+#[tauri::command]
+pub fn get_last_health(app: tauri::AppHandle) -> Option<crate::health::HealthSnapshot> {
+    crate::health::last(&app)
+}
+
+/// Suspend the backend process (low power mode) without stopping it
+/// Emits `backend://suspend_changed`; no requests should be sent until `resume_backend`
+#[tauri::command]
+pub fn suspend_backend(app: tauri::AppHandle) -> Result<(), String> {
+    crate::suspend::suspend(&app)
+}
+
+/// Resume a backend suspended with `suspend_backend`
+#[tauri::command]
+pub fn resume_backend(app: tauri::AppHandle) -> Result<(), String> {
+    crate::suspend::resume(&app)
+}
+
+/// Check the code signature of the backend executable used during the last startup
+#[tauri::command]
+pub async fn verify_backend_signature(
+    app: tauri::AppHandle,
+) -> Result<crate::signing::SignatureCheck, String> {
+    let info = crate::diagnostics::startup_info(&app);
+    let path = match info.backend_mode {
+        Some(crate::diagnostics::BackendMode::Bundled { executable }) => Some(executable),
+        _ => info.discovery.selected,
+    }
+    .ok_or("No backend executable was used")?;
+    tauri::async_runtime::spawn_blocking(move || crate::signing::verify(&path))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Restart the backend against a fresh, migrated temporary database to reproduce issues from scratch
+/// The real database is untouched; `end_clean_session` switches back to it
+#[tauri::command]
+pub async fn start_clean_session(app: tauri::AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || crate::clean_session::start(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Restore the real database after `start_clean_session` and delete the temporary one
+#[tauri::command]
+pub async fn end_clean_session(app: tauri::AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || crate::clean_session::end(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Change the startup timeout, initial poll interval and health request timeout (milliseconds)
+/// Values are clamped to a sane range and apply from the next backend (re)start
+#[tauri::command]
+pub fn set_startup_params(
+    app: tauri::AppHandle,
+    timeout_ms: u64,
+    poll_ms: u64,
+    req_timeout_ms: u64,
+) -> Result<(), String> {
+    crate::health::set_startup_params(&app, timeout_ms, poll_ms, req_timeout_ms).map(|_| ())
+}
+
+/// Kill a stuck first-run venv/pip setup; emits `backend://setup_cancelled`
+#[tauri::command]
+pub fn cancel_setup(app: tauri::AppHandle) -> Result<(), String> {
+    crate::setup::cancel(&app)
+}
+
+/// Report what exists at each path (type, size, executable architecture) to diagnose packaging
+#[tauri::command]
+pub async fn probe_paths(paths: Vec<String>) -> Result<Vec<crate::diagnostics::PathProbe>, String> {
+    let paths: Vec<std::path::PathBuf> = paths.into_iter().map(Into::into).collect();
+    tauri::async_runtime::spawn_blocking(move || crate::diagnostics::probe_paths(&paths))
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Download the backend's dependencies into `backend/wheels` so they can be installed offline later
+#[tauri::command]
+pub async fn cache_dependencies(app: tauri::AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || crate::setup::cache_dependencies(&app).map(|_| ()))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Run Django's `check` against the backend and return the warnings and errors it reports
+#[tauri::command]
+pub async fn backend_check(app: tauri::AppHandle) -> Result<Vec<String>, String> {
+    tauri::async_runtime::spawn_blocking(move || crate::management::check(&app))
+        .await
+        .map_err(|e| e.to_string())?
+}
+
+/// Generate a new Django `SECRET_KEY` and restart the backend to apply it
+/// This logs everyone out: existing sessions and signed tokens become invalid
+#[tauri::command]
+pub async fn rotate_secret_key(app: tauri::AppHandle) -> Result<(), String> {
+    tauri::async_runtime::spawn_blocking(move || {
+        crate::secret::rotate(&app)?;
+        crate::restart_backend(&app)
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
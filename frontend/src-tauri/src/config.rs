@@ -0,0 +1,476 @@
+use log::{error, info};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::sync::Mutex;
+use tauri::Manager;
+
+/// Which kind of backend to launch
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LaunchMode {
+    /// The bundled executable if one is found, otherwise `manage.py` with Python
+    #[default]
+    Auto,
+    /// Only the bundled executable; fail if there is none
+    Bundled,
+    /// Only `manage.py` with Python, even when an executable exists (to test source changes)
+    Python,
+}
+
+/// Whether the backend serves HTTPS; health checks and the frontend's API URL follow it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BackendTls {
+    /// Plain HTTP
+    #[default]
+    Off,
+    /// HTTPS with a certificate the system trusts
+    On,
+    /// HTTPS with a self-signed certificate, accepted only when the backend is on loopback
+    SelfSigned,
+}
+
+/// User-editable settings persisted in `<app_data_dir>/config.toml`
+/// Missing keys fall back to their defaults
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// Run Django with DEBUG enabled (detailed error pages); off by default
+    pub debug: bool,
+    /// Initial log level ("error", "warn", "info", "debug" or "trace")
+    pub log_level: String,
+    /// Profile (database) that was active on the last run
+    pub active_profile: String,
+    /// Ask before applying migrations to an existing database instead of migrating at startup
+    pub migrate_confirm: bool,
+    /// Hours between automatic backups while the app runs; 0 disables them
+    pub auto_backup_interval_hours: u64,
+    /// Number of backups to keep; older ones are deleted after a new backup
+    pub max_backups: usize,
+    /// Gzip backups older than this many days to save space; 0 keeps them uncompressed
+    pub compress_backups_after_days: u64,
+    /// Minutes between automatic WAL checkpoints, keeping the `-wal` file from growing; 0 disables them
+    pub wal_checkpoint_interval_minutes: u64,
+    /// Status codes the health endpoint may return when ready; empty accepts any 2xx
+    pub health_statuses: Vec<u16>,
+    /// Text the health response body must contain when ready; empty skips the body check
+    pub health_body_contains: String,
+    /// Only report the backend as ready once startup migrations have finished, not just when healthy
+    pub ready_after_migrations: bool,
+    /// Allow killing processes that hold the backend port; when off, a busy port is avoided instead
+    pub manage_port: bool,
+    /// Port the backend is started on; a busy port is still resolved by `manage_port` at startup
+    pub backend_port: u16,
+    /// Whether the backend was set up to serve HTTPS ("off", "on" or "self_signed"); the app
+    /// doesn't configure TLS itself, this only makes health checks use `https://`
+    pub backend_tls: BackendTls,
+    /// IP address the backend binds to
+    pub backend_host: String,
+    /// Required to bind `backend_host` to a non-loopback address, exposing the budget to the network
+    pub allow_remote_access: bool,
+    /// Directories the backend executable may be found in, besides the bundled resources
+    /// Empty keeps the built-in search locations (e.g. project folders in the home directory)
+    pub discovery_roots: Vec<PathBuf>,
+    /// Only launch backend executables outside the app bundle if they are code-signed
+    /// (Authenticode on Windows, codesign on macOS; other platforms refuse them)
+    pub verify_backend_signature: bool,
+    /// Text the signer (certificate subject or codesign authority) must contain; empty accepts any valid signature
+    pub backend_signer: String,
+    /// Emit every backend output line as `backend://stdout` / `backend://stderr` for an in-app console
+    pub dev_console: bool,
+    /// Run Django's `check` before starting the server and refuse to start on errors
+    pub preflight_check: bool,
+    /// Open the database read-only: no migrations and no writes, e.g. for viewing a backup
+    pub read_only: bool,
+    /// Record CPU time of the discovery and migration phases to flag ones dominated by I/O wait
+    pub startup_io_diagnostics: bool,
+    /// Launch the bundled executable, the Python source tree, or whichever is found ("auto")
+    pub backend_mode: LaunchMode,
+    /// How many times a failed backend spawn is retried (0 fails immediately, at most 10)
+    pub startup_max_retries: u32,
+    /// Delay before the first spawn retry in milliseconds, doubled for each further one (at most 10000)
+    pub startup_backoff_base_ms: u64,
+}
+
+/// Upper bound for `startup_max_retries`
+const MAX_STARTUP_RETRIES: u32 = 10;
+
+/// Upper bound for `startup_backoff_base_ms`
+const MAX_STARTUP_BACKOFF_BASE_MS: u64 = 10_000;
+
+impl Default for Config {
+    fn default() -> Self {
+        Self {
+            debug: false,
+            log_level: "info".to_string(),
+            active_profile: crate::profiles::DEFAULT_PROFILE.to_string(),
+            migrate_confirm: false,
+            auto_backup_interval_hours: 0,
+            max_backups: 10,
+            compress_backups_after_days: 0,
+            wal_checkpoint_interval_minutes: 0,
+            health_statuses: Vec::new(),
+            health_body_contains: String::new(),
+            ready_after_migrations: false,
+            manage_port: true,
+            backend_port: crate::port::DEFAULT_BACKEND_PORT,
+            backend_tls: BackendTls::Off,
+            backend_host: "127.0.0.1".to_string(),
+            allow_remote_access: false,
+            discovery_roots: Vec::new(),
+            verify_backend_signature: false,
+            backend_signer: String::new(),
+            dev_console: false,
+            preflight_check: false,
+            read_only: false,
+            startup_io_diagnostics: false,
+            backend_mode: LaunchMode::Auto,
+            startup_max_retries: 3,
+            startup_backoff_base_ms: 500,
+        }
+    }
+}
+
+/// Outcome of reloading the config file at runtime
+#[derive(Debug, Clone, Serialize)]
+pub struct ReloadedConfig {
+    pub config: Config,
+    /// Changed settings that only take effect after the backend restarts
+    pub pending_restart: Vec<String>,
+    /// Problems found in the file; the affected keys use their defaults
+    pub errors: Vec<String>,
+}
+
+/// Location of the config file inside the app data directory
+pub fn config_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+    crate::storage::app_data_dir(app)
+        .ok()
+        .map(|dir| dir.join("config.toml"))
+}
+
+/// Load the config file, falling back to defaults if it is missing
+/// Invalid keys fall back to their defaults individually; the problems found are returned
+/// alongside the config so they can be reported instead of silently ignored
+pub fn load(app: &tauri::AppHandle) -> (Config, Vec<String>) {
+    let Some(path) = config_path(app) else {
+        return (Config::default(), Vec::new());
+    };
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => {
+            let (config, errors) = parse(&contents);
+            info!("Loaded config from {:?}", path);
+            (config, errors)
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => (Config::default(), Vec::new()),
+        Err(e) => (
+            Config::default(),
+            vec![format!("Could not read {:?}: {}", path, e)],
+        ),
+    }
+}
+
+/// Parse config file contents key by key, so one bad value doesn't discard the rest
+fn parse(contents: &str) -> (Config, Vec<String>) {
+    let table: toml::Table = match contents.parse() {
+        Ok(table) => table,
+        Err(e) => return (Config::default(), vec![format!("Invalid TOML: {}", e)]),
+    };
+
+    let mut errors = Vec::new();
+    let mut merged = match toml::Value::try_from(Config::default()) {
+        Ok(toml::Value::Table(defaults)) => defaults,
+        _ => toml::Table::new(),
+    };
+    for (key, value) in table {
+        if !merged.contains_key(&key) {
+            errors.push(format!("Unknown setting '{}'", key));
+            continue;
+        }
+        let mut candidate = merged.clone();
+        candidate.insert(key.clone(), value);
+        match toml::Value::Table(candidate.clone()).try_into::<Config>() {
+            Ok(_) => merged = candidate,
+            Err(e) => errors.push(format!(
+                "Invalid value for '{}': {}, using the default",
+                key,
+                e.message()
+            )),
+        }
+    }
+
+    let mut config = toml::Value::Table(merged)
+        .try_into::<Config>()
+        .unwrap_or_default();
+    errors.extend(validate(&mut config));
+    (config, errors)
+}
+
+/// Check values that parse but make no sense, resetting them to their defaults
+fn validate(config: &mut Config) -> Vec<String> {
+    let defaults = Config::default();
+    let mut errors = Vec::new();
+
+    if let Err(e) = crate::logging::parse_level(&config.log_level) {
+        errors.push(format!("log_level: {}, using the default", e));
+        config.log_level = defaults.log_level;
+    }
+    if let Err(e) = crate::profiles::validate_profile_name(&config.active_profile) {
+        errors.push(format!("active_profile: {}, using the default", e));
+        config.active_profile = defaults.active_profile;
+    }
+    if let Some(status) = config
+        .health_statuses
+        .iter()
+        .find(|status| !(100..=599).contains(*status))
+    {
+        errors.push(format!(
+            "health_statuses: {} is not an HTTP status code, using the default",
+            status
+        ));
+        config.health_statuses = defaults.health_statuses;
+    }
+    if config.backend_port < crate::port::MIN_BACKEND_PORT {
+        errors.push(format!(
+            "backend_port: {} is below {}, using the default",
+            config.backend_port,
+            crate::port::MIN_BACKEND_PORT
+        ));
+        config.backend_port = defaults.backend_port;
+    }
+    match config.backend_host.parse::<std::net::IpAddr>() {
+        Err(_) => {
+            errors.push(format!(
+                "backend_host: '{}' is not an IP address, using the default",
+                config.backend_host
+            ));
+            config.backend_host = defaults.backend_host;
+        }
+        Ok(host) if !host.is_loopback() && !config.allow_remote_access => {
+            errors.push(format!(
+                "backend_host: binding to {} exposes the backend to the network and requires allow_remote_access = true, using the default",
+                host
+            ));
+            config.backend_host = defaults.backend_host;
+        }
+        Ok(_) => {}
+    }
+    if let Some(root) = config
+        .discovery_roots
+        .iter()
+        .find(|root| !root.is_absolute())
+    {
+        errors.push(format!(
+            "discovery_roots: {:?} is not an absolute path, using the default",
+            root
+        ));
+        config.discovery_roots = defaults.discovery_roots;
+    }
+    if config.startup_max_retries > MAX_STARTUP_RETRIES {
+        errors.push(format!(
+            "startup_max_retries: {} is above the maximum of {}, using the default",
+            config.startup_max_retries, MAX_STARTUP_RETRIES
+        ));
+        config.startup_max_retries = defaults.startup_max_retries;
+    }
+    if config.startup_backoff_base_ms > MAX_STARTUP_BACKOFF_BASE_MS {
+        errors.push(format!(
+            "startup_backoff_base_ms: {} is above the maximum of {}, using the default",
+            config.startup_backoff_base_ms, MAX_STARTUP_BACKOFF_BASE_MS
+        ));
+        config.startup_backoff_base_ms = defaults.startup_backoff_base_ms;
+    }
+    errors
+}
+
+/// Log config problems prominently and emit `backend://config_error` so the UI can show them
+pub fn report_errors(app: &tauri::AppHandle, errors: &[String]) {
+    if errors.is_empty() {
+        return;
+    }
+    for error in errors {
+        error!("Config error: {}", error);
+    }
+    crate::events::emit(
+        app,
+        "backend://config_error",
+        serde_json::json!({ "errors": errors }),
+    );
+}
+
+/// Write the config back to disk
+pub fn save(app: &tauri::AppHandle, config: &Config) -> Result<(), String> {
+    let path = config_path(app).ok_or("Could not resolve config file location")?;
+    let dir = path.parent().ok_or("Config file has no parent directory")?;
+    let contents = toml::to_string_pretty(config).map_err(|e| e.to_string())?;
+    // Another instance may be writing the same file during the single-instance race
+    crate::app_lock::with_lock(dir, || {
+        std::fs::write(&path, contents).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+    })?
+}
+
+/// Snapshot of the config currently held in app state
+pub fn current(app: &tauri::AppHandle) -> Config {
+    app.try_state::<Mutex<Config>>()
+        .and_then(|state| state.lock().ok().map(|config| config.clone()))
+        .unwrap_or_default()
+}
+
+/// Modify the in-memory config and persist the result
+pub fn update(app: &tauri::AppHandle, change: impl FnOnce(&mut Config)) -> Result<Config, String> {
+    let state = app
+        .try_state::<Mutex<Config>>()
+        .ok_or("Config is not initialized")?;
+    let mut config = state.lock().map_err(|e| e.to_string())?;
+    change(&mut config);
+    save(app, &config)?;
+    Ok(config.clone())
+}
+
+/// Re-read the config file and apply what can change live
+/// The log level applies immediately and health/backup settings are read on every use;
+/// settings used when the backend is launched are reported as pending a restart, with the
+/// state the restart reads (active database, port) updated so the restart actually applies them
+pub fn reload(app: &tauri::AppHandle) -> Result<ReloadedConfig, String> {
+    let (mut config, mut errors) = load(app);
+    let previous = current(app);
+
+    let mut pending_restart = Vec::new();
+    if config.debug != previous.debug {
+        pending_restart.push("debug".to_string());
+    }
+    if config.active_profile != previous.active_profile {
+        // A clean session restores the database it replaced when it ends, which would undo
+        // the switch, so the live config keeps the session's profile and the new one is only
+        // picked up at the next launch
+        if crate::clean_session::is_active(app) {
+            errors.push("active_profile: ignored while a clean session is running".to_string());
+            config.active_profile = previous.active_profile.clone();
+        } else {
+            let data_dir = crate::storage::app_data_dir(app)?;
+            crate::set_active_db_path(app, crate::profiles::resolve_db_path(&data_dir, &config));
+            pending_restart.push("active_profile".to_string());
+        }
+    }
+    if config.migrate_confirm != previous.migrate_confirm {
+        pending_restart.push("migrate_confirm".to_string());
+    }
+    if config.backend_port != previous.backend_port {
+        let running_port = crate::port::current_port(app);
+        // The running backend holds its own port, so only a different one has to be free
+        if config.backend_port == running_port || crate::port::is_port_free(config.backend_port) {
+            crate::port::set_current_port(app, config.backend_port);
+            crate::events::emit(app, "backend://port_changed", config.backend_port);
+            pending_restart.push("backend_port".to_string());
+        } else {
+            errors.push(format!(
+                "backend_port: port {} is already in use, keeping {}",
+                config.backend_port, previous.backend_port
+            ));
+            config.backend_port = previous.backend_port;
+        }
+    }
+    if config.backend_tls != previous.backend_tls {
+        pending_restart.push("backend_tls".to_string());
+    }
+    if config.backend_host != previous.backend_host {
+        pending_restart.push("backend_host".to_string());
+    }
+    if config.discovery_roots != previous.discovery_roots {
+        pending_restart.push("discovery_roots".to_string());
+    }
+    if config.dev_console != previous.dev_console {
+        pending_restart.push("dev_console".to_string());
+    }
+    if config.read_only != previous.read_only {
+        pending_restart.push("read_only".to_string());
+    }
+    if config.backend_mode != previous.backend_mode {
+        pending_restart.push("backend_mode".to_string());
+    }
+    if config.log_level != previous.log_level {
+        crate::logging::apply_config_level(&config.log_level);
+    }
+    report_errors(app, &errors);
+
+    let state = app
+        .try_state::<Mutex<Config>>()
+        .ok_or("Config is not initialized")?;
+    *state.lock().map_err(|e| e.to_string())? = config.clone();
+    info!(
+        "Config reloaded ({} setting(s) pending restart)",
+        pending_restart.len()
+    );
+
+    Ok(ReloadedConfig {
+        config,
+        pending_restart,
+        errors,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn valid_settings_are_read_without_errors() {
+        let (config, errors) = parse("backend_port = 8123\nlog_level = \"debug\"\n");
+        assert!(errors.is_empty(), "{:?}", errors);
+        assert_eq!(config.backend_port, 8123);
+        assert_eq!(config.log_level, "debug");
+    }
+
+    #[test]
+    fn out_of_range_ports_fall_back_to_the_default() {
+        let (config, errors) = parse("backend_port = 80\n");
+        assert_eq!(config.backend_port, Config::default().backend_port);
+        assert!(
+            errors.iter().any(|e| e.starts_with("backend_port")),
+            "{:?}",
+            errors
+        );
+
+        // Doesn't fit a u16 at all
+        let (config, errors) = parse("backend_port = 70000\n");
+        assert_eq!(config.backend_port, Config::default().backend_port);
+        assert!(
+            errors.iter().any(|e| e.contains("'backend_port'")),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn unknown_log_levels_fall_back_to_the_default() {
+        let (config, errors) = parse("log_level = \"chatty\"\n");
+        assert_eq!(config.log_level, Config::default().log_level);
+        assert!(
+            errors.iter().any(|e| e.starts_with("log_level")),
+            "{:?}",
+            errors
+        );
+    }
+
+    #[test]
+    fn unknown_keys_are_reported_and_the_rest_is_kept() {
+        let (config, errors) = parse("colour = \"blue\"\nbackend_port = 8123\n");
+        assert_eq!(errors, vec!["Unknown setting 'colour'".to_string()]);
+        assert_eq!(config.backend_port, 8123);
+    }
+
+    #[test]
+    fn invalid_values_are_reset_by_validate() {
+        let mut config = Config {
+            startup_max_retries: MAX_STARTUP_RETRIES + 1,
+            backend_host: "not an address".to_string(),
+            ..Config::default()
+        };
+        let errors = validate(&mut config);
+        assert_eq!(errors.len(), 2, "{:?}", errors);
+        assert_eq!(
+            config.startup_max_retries,
+            Config::default().startup_max_retries
+        );
+        assert_eq!(config.backend_host, Config::default().backend_host);
+    }
+}
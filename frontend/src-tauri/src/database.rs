@@ -0,0 +1,142 @@
+use rusqlite::{OpenFlags, OptionalExtension};
+use serde::Serialize;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+/// First 16 bytes of every SQLite 3 database file
+const SQLITE_MAGIC: &[u8; 16] = b"SQLite format 3\0";
+
+/// A row of Django's `django_migrations` table
+#[derive(Debug, Clone, Serialize)]
+pub struct MigrationEntry {
+    pub app: String,
+    pub name: String,
+    /// Timestamp as stored by Django
+    pub applied: String,
+}
+
+/// What a database file contains, checked without switching to it
+#[derive(Debug, Clone, Serialize)]
+pub struct DatabaseInspection {
+    pub path: PathBuf,
+    /// Whether the file looks safe to restore or switch to
+    pub valid: bool,
+    pub size: Option<u64>,
+    /// Whether the file starts with the SQLite header
+    pub valid_header: bool,
+    /// `quick_check` result: "ok", or the first problem found
+    pub integrity: Option<String>,
+    /// Number of rows in `django_migrations`; `None` when the table is missing
+    pub migration_count: Option<u64>,
+    pub latest_migration: Option<MigrationEntry>,
+    /// Why the inspection stopped early, if it did
+    pub error: Option<String>,
+}
+
+/// Inspect a database file read-only: header, quick integrity check and latest Django migration
+/// Problems are reported in the result rather than as an error so the UI can show them
+pub fn inspect(path: &Path) -> DatabaseInspection {
+    let mut inspection = DatabaseInspection {
+        path: path.to_path_buf(),
+        valid: false,
+        size: std::fs::metadata(path).ok().map(|metadata| metadata.len()),
+        valid_header: false,
+        integrity: None,
+        migration_count: None,
+        latest_migration: None,
+        error: None,
+    };
+    if let Err(e) = inspect_into(path, &mut inspection) {
+        inspection.error = Some(e);
+    }
+    inspection.valid = inspection.error.is_none()
+        && inspection.valid_header
+        && inspection.integrity.as_deref() == Some("ok");
+    inspection
+}
+
+/// Whether the file starts with the SQLite header; empty and truncated files don't
+pub fn has_sqlite_header(path: &Path) -> std::io::Result<bool> {
+    let mut header = [0u8; 16];
+    match std::fs::File::open(path).and_then(|mut file| file.read_exact(&mut header)) {
+        Ok(()) => Ok(&header == SQLITE_MAGIC),
+        Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+fn inspect_into(path: &Path, inspection: &mut DatabaseInspection) -> Result<(), String> {
+    inspection.valid_header =
+        has_sqlite_header(path).map_err(|e| format!("Could not read {:?}: {}", path, e))?;
+    if !inspection.valid_header {
+        return Err(format!("{:?} is not an SQLite database", path));
+    }
+
+    let connection = rusqlite::Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Could not open database {:?}: {}", path, e))?;
+    inspection.integrity = Some(
+        connection
+            .query_row("PRAGMA quick_check(1)", [], |row| row.get(0))
+            .map_err(|e| format!("Integrity check failed: {}", e))?,
+    );
+
+    let has_migrations = connection
+        .query_row(
+            "SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'django_migrations'",
+            [],
+            |_| Ok(()),
+        )
+        .optional()
+        .map_err(|e| e.to_string())?
+        .is_some();
+    if !has_migrations {
+        return Ok(());
+    }
+    inspection.migration_count = Some(
+        connection
+            .query_row("SELECT COUNT(*) FROM django_migrations", [], |row| {
+                row.get(0)
+            })
+            .map_err(|e| e.to_string())?,
+    );
+    inspection.latest_migration = connection
+        .query_row(
+            "SELECT app, name, applied FROM django_migrations ORDER BY applied DESC, id DESC LIMIT 1",
+            [],
+            |row| {
+                Ok(MigrationEntry {
+                    app: row.get(0)?,
+                    name: row.get(1)?,
+                    applied: row.get(2)?,
+                })
+            },
+        )
+        .optional()
+        .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Every applied migration in the database, oldest first, read directly from `django_migrations`
+pub fn migration_history(path: &Path) -> Result<Vec<MigrationEntry>, String> {
+    let connection = rusqlite::Connection::open_with_flags(
+        path,
+        OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+    )
+    .map_err(|e| format!("Could not open database {:?}: {}", path, e))?;
+    let mut statement = connection
+        .prepare("SELECT app, name, applied FROM django_migrations ORDER BY applied, id")
+        .map_err(|e| format!("Could not read django_migrations: {}", e))?;
+    statement
+        .query_map([], |row| {
+            Ok(MigrationEntry {
+                app: row.get(0)?,
+                name: row.get(1)?,
+                applied: row.get(2)?,
+            })
+        })
+        .and_then(|rows| rows.collect())
+        .map_err(|e| format!("Could not read django_migrations: {}", e))
+}
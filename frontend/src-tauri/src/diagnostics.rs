@@ -0,0 +1,513 @@
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+use tauri::Manager;
+
+/// How the backend was launched during the most recent startup
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "mode", rename_all = "snake_case")]
+pub enum BackendMode {
+    Bundled {
+        executable: PathBuf,
+    },
+    Python {
+        interpreter: PathBuf,
+        backend_dir: PathBuf,
+    },
+}
+
+/// A path probed for the backend executable
+#[derive(Debug, Clone, Serialize)]
+pub struct DiscoveryCandidate {
+    pub path: PathBuf,
+    pub exists: bool,
+    pub size: Option<u64>,
+}
+
+/// Every path probed for the backend executable and the one that was chosen, if any
+/// `selected` stays empty when no executable was usable and the Python fallback ran
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct DiscoveryReport {
+    pub candidates: Vec<DiscoveryCandidate>,
+    pub selected: Option<PathBuf>,
+    /// `backend.manifest.json` that declared the executable, when discovery was skipped
+    pub manifest: Option<PathBuf>,
+}
+
+/// What was found for the bundled backend executable
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum BinaryStatus {
+    /// A real executable that passes the launch checks
+    Real { path: PathBuf },
+    /// Only a stub smaller than 1KB, as left by a build that didn't produce the backend
+    Placeholder { path: PathBuf, size: u64 },
+    /// No executable anywhere; only the Python source tree can run
+    Missing,
+}
+
+/// What exists at a path, for diagnosing packaging layouts on a user's machine
+#[derive(Debug, Clone, Serialize)]
+pub struct PathProbe {
+    pub path: PathBuf,
+    pub exists: bool,
+    pub is_file: bool,
+    pub is_dir: bool,
+    pub size: Option<u64>,
+    /// CPU architecture of an ELF, PE or Mach-O executable (e.g. "x86_64")
+    pub arch: Option<String>,
+}
+
+/// Describe each path: existence, type, size and executable architecture
+pub fn probe_paths(paths: &[PathBuf]) -> Vec<PathProbe> {
+    paths
+        .iter()
+        .map(|path| {
+            let metadata = std::fs::metadata(path).ok();
+            let is_file = metadata.as_ref().is_some_and(|m| m.is_file());
+            PathProbe {
+                path: path.clone(),
+                exists: metadata.is_some(),
+                is_file,
+                is_dir: metadata.as_ref().is_some_and(|m| m.is_dir()),
+                size: metadata.as_ref().filter(|_| is_file).map(|m| m.len()),
+                arch: if is_file { executable_arch(path) } else { None },
+            }
+        })
+        .collect()
+}
+
+/// Read the machine field from an executable's header
+fn executable_arch(path: &Path) -> Option<String> {
+    use std::io::{Read, Seek, SeekFrom};
+
+    let mut file = std::fs::File::open(path).ok()?;
+    let mut header = [0u8; 64];
+    let read = file.read(&mut header).ok()?;
+    let header = &header[..read];
+
+    // ELF: e_machine at offset 18, in the byte order given by EI_DATA
+    if header.starts_with(b"\x7fELF") && header.len() >= 20 {
+        let bytes = [header[18], header[19]];
+        let machine = if header[5] == 2 {
+            u16::from_be_bytes(bytes)
+        } else {
+            u16::from_le_bytes(bytes)
+        };
+        return Some(
+            match machine {
+                0x03 => "x86",
+                0x3e => "x86_64",
+                0x28 => "arm",
+                0xb7 => "aarch64",
+                0xf3 => "riscv",
+                _ => return Some(format!("elf machine {:#x}", machine)),
+            }
+            .to_string(),
+        );
+    }
+
+    // PE: the "PE\0\0" header offset is at 0x3c, followed by the machine field
+    if header.starts_with(b"MZ") && header.len() >= 0x40 {
+        let pe_offset =
+            u32::from_le_bytes([header[0x3c], header[0x3d], header[0x3e], header[0x3f]]);
+        let mut pe = [0u8; 6];
+        file.seek(SeekFrom::Start(u64::from(pe_offset))).ok()?;
+        file.read_exact(&mut pe).ok()?;
+        if &pe[..4] != b"PE\0\0" {
+            return None;
+        }
+        let machine = u16::from_le_bytes([pe[4], pe[5]]);
+        return Some(
+            match machine {
+                0x014c => "x86",
+                0x8664 => "x86_64",
+                0xaa64 => "aarch64",
+                _ => return Some(format!("pe machine {:#x}", machine)),
+            }
+            .to_string(),
+        );
+    }
+
+    // Mach-O (64-bit, little endian as on all current Macs) or a universal binary
+    if header.len() >= 8 {
+        let magic = u32::from_le_bytes([header[0], header[1], header[2], header[3]]);
+        if magic == 0xfeedfacf {
+            let cpu = u32::from_le_bytes([header[4], header[5], header[6], header[7]]);
+            return Some(
+                match cpu {
+                    0x0100_0007 => "x86_64",
+                    0x0100_000c => "aarch64",
+                    _ => return Some(format!("mach-o cpu {:#x}", cpu)),
+                }
+                .to_string(),
+            );
+        }
+        if magic == 0xbebafeca {
+            return Some("universal".to_string());
+        }
+    }
+    None
+}
+
+/// Threads used to stat discovery candidates; the work is I/O-bound, not CPU-bound
+const PROBE_THREADS: usize = 8;
+
+/// Stat each path concurrently and return the results in input order, so priority is kept
+pub fn probe_candidates(paths: &[PathBuf]) -> Vec<DiscoveryCandidate> {
+    let probe = |path: &PathBuf| {
+        let size = std::fs::metadata(path).ok().map(|metadata| metadata.len());
+        DiscoveryCandidate {
+            path: path.clone(),
+            exists: size.is_some(),
+            size,
+        }
+    };
+    let chunk_size = paths.len().div_ceil(PROBE_THREADS).max(1);
+    std::thread::scope(|scope| {
+        let workers: Vec<_> = paths
+            .chunks(chunk_size)
+            .map(|chunk| {
+                (
+                    chunk,
+                    scope.spawn(move || chunk.iter().map(probe).collect()),
+                )
+            })
+            .collect();
+        workers
+            .into_iter()
+            .flat_map(|(chunk, worker)| {
+                // A panicked worker falls back to probing its chunk on this thread
+                worker
+                    .join()
+                    .unwrap_or_else(|_| chunk.iter().map(probe).collect::<Vec<_>>())
+            })
+            .collect()
+    })
+}
+
+/// Facts detected while starting the backend, reused for reports instead of re-probing
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StartupInfo {
+    pub backend_mode: Option<BackendMode>,
+    pub python_version: Option<String>,
+    pub database_path: Option<PathBuf>,
+    pub discovery: DiscoveryReport,
+    /// Directory used because the app data directory could not be resolved
+    pub storage_fallback: Option<PathBuf>,
+    /// Shell line reproducing the last backend launch, without secrets
+    pub backend_command: Option<String>,
+}
+
+/// Update the startup info stored in app state
+pub fn record_startup_info(app: &tauri::AppHandle, update: impl FnOnce(&mut StartupInfo)) {
+    if let Some(state) = app.try_state::<Mutex<StartupInfo>>()
+        && let Ok(mut info) = state.lock()
+    {
+        update(&mut info);
+    }
+}
+
+/// Snapshot of the startup info stored in app state
+pub fn startup_info(app: &tauri::AppHandle) -> StartupInfo {
+    app.try_state::<Mutex<StartupInfo>>()
+        .and_then(|state| state.lock().ok().map(|info| info.clone()))
+        .unwrap_or_default()
+}
+
+/// Ask a Python interpreter for its version string (e.g. "Python 3.12.1")
+pub fn detect_python_version(python_cmd: &Path) -> Option<String> {
+    let output = crate::process::configure_command(&mut Command::new(python_cmd))
+        .arg("--version")
+        .output()
+        .ok()?;
+    // Older Python versions print the version to stderr
+    let text = if output.stdout.is_empty() {
+        String::from_utf8_lossy(&output.stderr).to_string()
+    } else {
+        String::from_utf8_lossy(&output.stdout).to_string()
+    };
+    let version = text.trim();
+    (!version.is_empty()).then(|| version.to_string())
+}
+
+/// What is in place for starting the backend, checked without launching anything
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct EnvReadiness {
+    /// Backend source tree used by the Python fallback
+    pub backend_dir: Option<PathBuf>,
+    pub python: Option<PathBuf>,
+    pub python_version: Option<String>,
+    /// Whether the backend source tree has a `.venv`
+    pub venv_present: bool,
+    pub django_importable: bool,
+    /// Usable bundled executable from the last discovery
+    pub backend_executable: Option<PathBuf>,
+}
+
+/// Check Python, the virtual environment, Django and the bundled executable
+pub fn check_environment(app: &tauri::AppHandle) -> EnvReadiness {
+    let info = startup_info(app);
+    let mut readiness = EnvReadiness {
+        // Anything under 1KB is a placeholder, as in discovery
+        backend_executable: info.discovery.selected.clone().or_else(|| {
+            info.discovery
+                .candidates
+                .iter()
+                .find(|candidate| candidate.size.is_some_and(|size| size >= 1024))
+                .map(|candidate| candidate.path.clone())
+        }),
+        ..Default::default()
+    };
+
+    readiness.backend_dir = match info.backend_mode {
+        Some(BackendMode::Python { backend_dir, .. }) => Some(backend_dir),
+        _ => std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().and_then(crate::find_backend_source_dir)),
+    };
+    let Some(backend_dir) = &readiness.backend_dir else {
+        return readiness;
+    };
+
+    let venv = backend_dir.join(".venv");
+    readiness.venv_present = venv.join("bin").join("python").exists()
+        || venv.join("Scripts").join("python.exe").exists();
+    readiness.python = crate::find_python(backend_dir);
+    if let Some(python) = &readiness.python {
+        readiness.python_version = detect_python_version(python);
+        readiness.django_importable = crate::check_backend_dependencies(python);
+    }
+    readiness
+}
+
+/// Build a Markdown environment summary ready to paste into a GitHub issue
+pub fn build_env_report(app: &tauri::AppHandle) -> String {
+    let info = startup_info(app);
+
+    let backend_mode = match &info.backend_mode {
+        Some(BackendMode::Bundled { executable }) => {
+            format!("bundled executable (`{}`)", executable.display())
+        }
+        Some(BackendMode::Python {
+            interpreter,
+            backend_dir,
+        }) => format!(
+            "Python (`{}` in `{}`)",
+            interpreter.display(),
+            backend_dir.display()
+        ),
+        None => "not started".to_string(),
+    };
+
+    let mut report = String::new();
+    let _ = writeln!(report, "### Environment");
+    let _ = writeln!(report);
+    let _ = writeln!(
+        report,
+        "- **OS:** {} ({})",
+        std::env::consts::OS,
+        std::env::consts::FAMILY
+    );
+    let _ = writeln!(report, "- **Arch:** {}", std::env::consts::ARCH);
+    let _ = writeln!(report, "- **App version:** {}", app.package_info().version);
+    let _ = writeln!(report, "- **Backend mode:** {}", backend_mode);
+    let _ = writeln!(
+        report,
+        "- **Python:** {}",
+        info.python_version.as_deref().unwrap_or("n/a")
+    );
+    if crate::logging::fallback_active() {
+        let _ = writeln!(
+            report,
+            "- **Logging:** stderr fallback (log plugin failed, no log file)"
+        );
+    }
+    let _ = writeln!(report);
+    let _ = writeln!(report, "### Configuration");
+    let _ = writeln!(report);
+    if let Some(fallback) = &info.storage_fallback {
+        let _ = writeln!(
+            report,
+            "- **Data directory fallback:** `{}` (app data directory unavailable)",
+            fallback.display()
+        );
+    }
+    let _ = writeln!(
+        report,
+        "- **Database path:** {}",
+        info.database_path
+            .as_ref()
+            .map(|p| format!("`{}`", p.display()))
+            .unwrap_or_else(|| "unknown".to_string())
+    );
+    let _ = writeln!(
+        report,
+        "- **Backend address:** {}",
+        std::net::SocketAddr::new(crate::backend_host(app), crate::port::current_port(app))
+    );
+    let io_bound: Vec<String> = app
+        .try_state::<crate::timing::StartupTimeline>()
+        .map(|timeline| timeline.snapshot())
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|phase| phase.io_bound)
+        .map(|phase| format!("{} ({}ms)", phase.name, phase.duration_ms))
+        .collect();
+    if !io_bound.is_empty() {
+        let _ = writeln!(
+            report,
+            "- **I/O-bound startup phases:** {}",
+            io_bound.join(", ")
+        );
+    }
+    report
+}
+
+/// CPU usage of the backend in percent of one core, summed over the process and its children
+/// (runserver's autoreloader serves requests from a child process)
+/// Blocks for sysinfo's minimum sampling interval to measure a delta
+pub fn backend_cpu_usage(pid: u32) -> f32 {
+    let mut system = System::new();
+    let refresh = ProcessRefreshKind::nothing().with_cpu();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+    std::thread::sleep(sysinfo::MINIMUM_CPU_UPDATE_INTERVAL);
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, refresh);
+
+    let pid = Pid::from_u32(pid);
+    system
+        .processes()
+        .values()
+        .filter(|process| process.pid() == pid || process.parent() == Some(pid))
+        .map(|process| process.cpu_usage())
+        .sum()
+}
+
+/// PID and start time of the managed backend process
+#[derive(Debug, Clone, Serialize)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    /// Spawn time in milliseconds since the Unix epoch
+    pub started_at_ms: u64,
+    pub uptime_ms: u64,
+}
+
+/// PID and spawn time of the most recently spawned backend
+#[derive(Default)]
+pub struct BackendSpawn(Mutex<Option<(u32, SystemTime, Instant)>>);
+
+/// Remember when the backend with `pid` was spawned
+pub fn record_spawn(app: &tauri::AppHandle, pid: u32) {
+    if let Some(state) = app.try_state::<BackendSpawn>()
+        && let Ok(mut spawn) = state.0.lock()
+    {
+        *spawn = Some((pid, SystemTime::now(), Instant::now()));
+    }
+}
+
+/// PID and uptime of the managed backend, or `None` when no backend is stored
+/// The spawn record only counts if it belongs to the stored process, so a stale one never shows
+pub fn process_info(app: &tauri::AppHandle) -> Option<ProcessInfo> {
+    let pid = crate::backend_pid(app)?;
+    let state = app.try_state::<BackendSpawn>()?;
+    let (spawned_pid, started_at, started) = (*state.0.lock().ok()?)?;
+    (spawned_pid == pid).then(|| ProcessInfo {
+        pid,
+        started_at_ms: started_at
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0),
+        uptime_ms: started.elapsed().as_millis() as u64,
+    })
+}
+
+/// Whether the frontend talks to the backend on the port it was started on
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectionReport {
+    /// Port the backend was started on
+    pub backend_port: u16,
+    /// Port the frontend sends API requests to, if it reported one
+    pub frontend_port: Option<u16>,
+    pub ports_match: bool,
+    /// Whether the health endpoint answered on the frontend's port (the backend's if unknown)
+    pub healthy: bool,
+    pub latency_ms: Option<u64>,
+    pub error: Option<String>,
+}
+
+/// Compare the backend's port with the frontend's and probe the health endpoint on the latter,
+/// the port API calls actually go to
+pub fn verify_connection(app: &tauri::AppHandle, frontend_port: Option<u16>) -> ConnectionReport {
+    let backend_port = crate::port::current_port(app);
+    let probed_port = frontend_port.unwrap_or(backend_port);
+    let url = crate::health_url(app, probed_port);
+    let probe = crate::health_client::shared(app)
+        .and_then(|client| crate::probe_health(app, &client, &url, &crate::config::current(app)));
+    ConnectionReport {
+        backend_port,
+        frontend_port,
+        ports_match: frontend_port.is_none_or(|port| port == backend_port),
+        healthy: probe.is_ok(),
+        latency_ms: probe.as_ref().ok().copied(),
+        error: probe.err(),
+    }
+}
+
+/// Most requests one benchmark may send
+const MAX_BENCHMARK_ITERATIONS: u32 = 1_000;
+
+/// Round-trip latencies of sequential health requests, in milliseconds
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BenchmarkResult {
+    pub iterations: u32,
+    /// Requests that failed or returned a non-success status; left out of the latencies
+    pub failures: u32,
+    pub min_ms: f64,
+    pub max_ms: f64,
+    pub avg_ms: f64,
+    pub p95_ms: f64,
+}
+
+/// Send `iterations` health requests one after another over the shared client and summarize
+/// their latency, separating backend round trips from frontend rendering in "slow app" reports
+pub fn benchmark_backend(
+    app: &tauri::AppHandle,
+    iterations: u32,
+) -> Result<BenchmarkResult, String> {
+    let iterations = iterations.clamp(1, MAX_BENCHMARK_ITERATIONS);
+    let client = crate::health_client::shared(app)?;
+    let url = crate::health_url(app, crate::port::current_port(app));
+    let timeout = crate::health::startup_params(app).request_timeout;
+    let config = crate::config::current(app);
+
+    let mut latencies = Vec::with_capacity(iterations as usize);
+    let mut failures = 0;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let response = client.get(&url, timeout);
+        let elapsed_ms = start.elapsed().as_secs_f64() * 1000.0;
+        // Same criteria as the startup poll and the watchdog, without filling the health history
+        match response.map(|response| crate::check_health_response(response, &config)) {
+            Ok(Ok(())) => latencies.push(elapsed_ms),
+            _ => failures += 1,
+        }
+    }
+    if latencies.is_empty() {
+        return Err(format!("All {} requests to {} failed", iterations, url));
+    }
+
+    latencies.sort_by(f64::total_cmp);
+    let p95_index = (latencies.len() * 95).div_ceil(100).saturating_sub(1);
+    Ok(BenchmarkResult {
+        iterations,
+        failures,
+        min_ms: latencies[0],
+        max_ms: latencies[latencies.len() - 1],
+        avg_ms: latencies.iter().sum::<f64>() / latencies.len() as f64,
+        p95_ms: latencies[p95_index],
+    })
+}
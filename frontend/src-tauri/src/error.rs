@@ -0,0 +1,44 @@
+use crate::port::PortOwner;
+use serde::Serialize;
+use std::fmt;
+use std::path::PathBuf;
+
+/// Errors from backend lifecycle operations that callers may want to react to
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "code", rename_all = "snake_case")]
+pub enum BackendError {
+    /// A filesystem operation did not finish in time (e.g. a disconnected network mount)
+    StorageUnavailable { operation: String, path: PathBuf },
+    /// A filesystem operation failed outright
+    Storage {
+        operation: String,
+        path: PathBuf,
+        message: String,
+    },
+    /// The backend port is held by a process that is not one of our backends
+    PortConflict { port: u16, owner: PortOwner },
+}
+
+impl fmt::Display for BackendError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BackendError::StorageUnavailable { operation, path } => write!(
+                f,
+                "Storage unavailable: {} on {:?} did not complete in time (is the drive or network share connected?)",
+                operation, path
+            ),
+            BackendError::Storage {
+                operation,
+                path,
+                message,
+            } => write!(f, "{} failed on {:?}: {}", operation, path, message),
+            BackendError::PortConflict { port, owner } => write!(
+                f,
+                "Port {} is in use by another application: {} (PID {})",
+                port, owner.name, owner.pid
+            ),
+        }
+    }
+}
+
+impl std::error::Error for BackendError {}
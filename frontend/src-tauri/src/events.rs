@@ -0,0 +1,67 @@
+use log::warn;
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{Emitter, Manager};
+
+/// Label of the window that receives startup/setup progress events; `None` broadcasts them
+#[derive(Default)]
+pub struct ProgressWindow(Mutex<Option<String>>);
+
+/// Route progress events to the window with `label` (e.g. a splash screen), or to all windows
+pub fn set_progress_window(app: &tauri::AppHandle, label: Option<String>) -> Result<(), String> {
+    if let Some(label) = &label
+        && app.get_webview_window(label).is_none()
+    {
+        return Err(format!("No window labelled '{}'", label));
+    }
+    let state = app
+        .try_state::<ProgressWindow>()
+        .ok_or("Progress window state is not initialized")?;
+    *state.0.lock().map_err(|e| e.to_string())? = label;
+    Ok(())
+}
+
+/// Emit a `backend://...` event to the frontend, logging instead of failing if delivery fails
+/// Object payloads get the current startup attempt's `attempt_id` added for correlation
+pub fn emit<S: Serialize + Clone>(app: &tauri::AppHandle, event: &str, payload: S) {
+    if let Some(payload) = prepare(event, payload)
+        && let Err(e) = app.emit(event, payload)
+    {
+        warn!("Failed to emit {} event: {}", event, e);
+    }
+}
+
+/// `emit` for startup/setup progress (backend output, pip lines), sent only to the progress window
+/// when one is set and still open, so other windows don't receive the noise
+pub fn emit_progress<S: Serialize + Clone>(app: &tauri::AppHandle, event: &str, payload: S) {
+    let target = app
+        .try_state::<ProgressWindow>()
+        .and_then(|state| state.0.lock().ok().and_then(|label| label.clone()))
+        .filter(|label| app.get_webview_window(label).is_some());
+    let Some(target) = target else {
+        emit(app, event, payload);
+        return;
+    };
+    if let Some(payload) = prepare(event, payload)
+        && let Err(e) = app.emit_to(target.as_str(), event, payload)
+    {
+        warn!("Failed to emit {} event to {}: {}", event, target, e);
+    }
+}
+
+/// Serialize the payload and tag objects with the startup attempt
+fn prepare<S: Serialize>(event: &str, payload: S) -> Option<serde_json::Value> {
+    let mut payload = match serde_json::to_value(payload) {
+        Ok(payload) => payload,
+        Err(e) => {
+            warn!("Failed to serialize {} event: {}", event, e);
+            return None;
+        }
+    };
+    if let (Some(fields), Some(id)) = (payload.as_object_mut(), crate::logging::attempt_id()) {
+        fields
+            .entry("attempt_id")
+            .or_insert(serde_json::Value::String(id));
+    }
+    Some(payload)
+}
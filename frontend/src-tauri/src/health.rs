@@ -0,0 +1,103 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tauri::Manager;
+
+/// Outcome of the most recent health probe
+#[derive(Debug, Clone, Serialize)]
+pub struct HealthSnapshot {
+    /// Unix time of the probe in milliseconds
+    pub checked_at_ms: u64,
+    pub healthy: bool,
+    pub latency_ms: u64,
+    /// HTTP status, if the backend answered at all
+    pub status: Option<u16>,
+    pub error: Option<String>,
+}
+
+/// Last health probe result, shared by startup, reconnects and pings
+#[derive(Default)]
+pub struct LastHealth(Mutex<Option<HealthSnapshot>>);
+
+/// Store a probe result in app state
+pub fn record(
+    app: &tauri::AppHandle,
+    latency_ms: u64,
+    status: Option<u16>,
+    result: &Result<(), String>,
+) {
+    let snapshot = HealthSnapshot {
+        checked_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0),
+        healthy: result.is_ok(),
+        latency_ms,
+        status,
+        error: result.as_ref().err().cloned(),
+    };
+    if let Some(state) = app.try_state::<LastHealth>()
+        && let Ok(mut last) = state.0.lock()
+    {
+        *last = Some(snapshot);
+    }
+}
+
+/// The most recent probe result, or `None` before the first probe
+pub fn last(app: &tauri::AppHandle) -> Option<HealthSnapshot> {
+    app.try_state::<LastHealth>()
+        .and_then(|state| state.0.lock().ok().and_then(|last| last.clone()))
+}
+
+/// Health-check and startup timings, adjustable at runtime so QA can simulate slow startups
+#[derive(Debug, Clone, Copy)]
+pub struct StartupParams {
+    /// How long startup waits for the backend to become healthy when no migration is running
+    pub startup_timeout: Duration,
+    /// First delay between health checks; doubles after every failed check, up to 1s
+    pub poll_interval: Duration,
+    /// Timeout for a single health check request
+    pub request_timeout: Duration,
+}
+
+impl Default for StartupParams {
+    fn default() -> Self {
+        Self {
+            startup_timeout: Duration::from_secs(30),
+            poll_interval: Duration::from_millis(100),
+            request_timeout: Duration::from_secs(2),
+        }
+    }
+}
+
+/// Startup parameters used by the next health poll, kept in app state
+#[derive(Default)]
+pub struct StartupParamsState(Mutex<StartupParams>);
+
+/// Current startup parameters, or the defaults if app state is not set up
+pub fn startup_params(app: &tauri::AppHandle) -> StartupParams {
+    app.try_state::<StartupParamsState>()
+        .and_then(|state| state.0.lock().ok().map(|params| *params))
+        .unwrap_or_default()
+}
+
+/// Replace the startup parameters, clamping each value to a sane range
+pub fn set_startup_params(
+    app: &tauri::AppHandle,
+    timeout_ms: u64,
+    poll_ms: u64,
+    req_timeout_ms: u64,
+) -> Result<StartupParams, String> {
+    let params = StartupParams {
+        startup_timeout: Duration::from_millis(timeout_ms.clamp(1_000, 600_000)),
+        poll_interval: Duration::from_millis(poll_ms.clamp(10, 1_000)),
+        request_timeout: Duration::from_millis(req_timeout_ms.clamp(100, 60_000)),
+    };
+    let state = app
+        .try_state::<StartupParamsState>()
+        .ok_or("Startup parameters are not available")?;
+    let mut current = state.0.lock().map_err(|e| e.to_string())?;
+    *current = params;
+    log::info!("Startup parameters set to {:?}", params);
+    Ok(params)
+}
@@ -0,0 +1,232 @@
+use std::sync::{Arc, Mutex};
+use tauri::Manager;
+
+/// Status and body of a health endpoint response
+#[derive(Debug)]
+pub struct HealthResponse {
+    pub status: u16,
+    pub body: String,
+}
+
+/// Health checks through reqwest (the default)
+#[cfg(all(feature = "reqwest-health", not(feature = "minimal-health")))]
+mod imp {
+    use super::HealthResponse;
+    use log::{error, warn};
+    use std::time::Duration;
+
+    /// Client-wide timeout for health requests; each probe also sets its own
+    const HEALTH_CHECK_TIMEOUT: Duration = Duration::from_secs(2);
+
+    /// How many times to try building the client before falling back to a default one
+    const BUILD_ATTEMPTS: u32 = 3;
+
+    pub struct HealthClient(reqwest::blocking::Client);
+
+    impl HealthClient {
+        /// Builder failures (e.g. a broken TLS setup) are retried and logged instead of silently degrading
+        /// `accept_invalid_certs` is for a loopback backend with a self-signed certificate only
+        pub fn build(accept_invalid_certs: bool) -> Result<Self, String> {
+            for attempt in 1..=BUILD_ATTEMPTS {
+                match reqwest::blocking::Client::builder()
+                    .timeout(HEALTH_CHECK_TIMEOUT)
+                    .danger_accept_invalid_certs(accept_invalid_certs)
+                    .build()
+                {
+                    Ok(client) => return Ok(Self(client)),
+                    Err(e) => {
+                        warn!(
+                            "Failed to build health check client (attempt {}/{}): {}",
+                            attempt, BUILD_ATTEMPTS, e
+                        );
+                        std::thread::sleep(Duration::from_millis(100));
+                    }
+                }
+            }
+            error!(
+                "Could not build health check client, retrying without client settings (requests keep their own timeout)"
+            );
+            reqwest::blocking::Client::builder()
+                .danger_accept_invalid_certs(accept_invalid_certs)
+                .build()
+                .map(Self)
+                .map_err(|e| e.to_string())
+        }
+
+        pub fn get(&self, url: &str, timeout: Duration) -> Result<HealthResponse, String> {
+            // The per-request timeout also bounds the fallback client, which has none of its own
+            let response = self
+                .0
+                .get(url)
+                .timeout(timeout)
+                .send()
+                .map_err(|e| e.to_string())?;
+            let status = response.status().as_u16();
+            let body = response
+                .text()
+                .map_err(|e| format!("could not read body: {}", e))?;
+            Ok(HealthResponse { status, body })
+        }
+    }
+}
+
+/// Health checks as a bare HTTP/1.0 GET over `TcpStream`, for builds without reqwest
+/// Enough for the plain-HTTP loopback endpoint; HTTP/1.0 keeps the server from chunking the body
+#[cfg(any(not(feature = "reqwest-health"), feature = "minimal-health"))]
+mod imp {
+    use super::HealthResponse;
+    use std::io::{Read, Write};
+    use std::net::{SocketAddr, TcpStream};
+    use std::time::Duration;
+
+    pub struct HealthClient;
+
+    impl HealthClient {
+        pub fn build(_accept_invalid_certs: bool) -> Result<Self, String> {
+            Ok(Self)
+        }
+
+        pub fn get(&self, url: &str, timeout: Duration) -> Result<HealthResponse, String> {
+            if url.starts_with("https://") {
+                return Err("HTTPS health checks need the reqwest-health feature".to_string());
+            }
+            let rest = url
+                .strip_prefix("http://")
+                .ok_or_else(|| format!("unsupported URL {}", url))?;
+            let (authority, path) = rest.split_at(rest.find('/').unwrap_or(rest.len()));
+            let addr: SocketAddr = authority
+                .parse()
+                .map_err(|_| format!("unsupported host in URL {}", url))?;
+
+            let mut stream =
+                TcpStream::connect_timeout(&addr, timeout).map_err(|e| e.to_string())?;
+            stream
+                .set_read_timeout(Some(timeout))
+                .and_then(|()| stream.set_write_timeout(Some(timeout)))
+                .map_err(|e| e.to_string())?;
+            let path = if path.is_empty() { "/" } else { path };
+            write!(
+                stream,
+                "GET {} HTTP/1.0\r\nHost: {}\r\nAccept: */*\r\n\r\n",
+                path, authority
+            )
+            .map_err(|e| e.to_string())?;
+            let mut raw = Vec::new();
+            stream
+                .read_to_end(&mut raw)
+                .map_err(|e| format!("could not read response: {}", e))?;
+            parse_response(&raw)
+        }
+    }
+
+    /// Split a raw HTTP/1.x response into status and body
+    /// A response that ends inside the headers or short of its `Content-Length` is an error
+    pub(super) fn parse_response(raw: &[u8]) -> Result<HealthResponse, String> {
+        let raw = String::from_utf8_lossy(raw);
+        let (head, body) = raw
+            .split_once("\r\n\r\n")
+            .ok_or("truncated HTTP response: headers did not end")?;
+        let mut lines = head.split("\r\n");
+        let status = lines
+            .next()
+            .filter(|status_line| status_line.starts_with("HTTP/"))
+            .and_then(|status_line| status_line.split_whitespace().nth(1))
+            .and_then(|code| code.parse().ok())
+            .ok_or("malformed HTTP response")?;
+
+        let content_length = lines
+            .filter_map(|line| line.split_once(':'))
+            .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+            .map(|(_, value)| value.trim().parse::<usize>())
+            .transpose()
+            .map_err(|_| "malformed Content-Length header")?;
+        let body = match content_length {
+            Some(length) if body.len() < length => {
+                return Err(format!(
+                    "truncated HTTP response: got {} of {} body bytes",
+                    body.len(),
+                    length
+                ));
+            }
+            Some(length) => body.get(..length).unwrap_or(body),
+            None => body,
+        };
+        Ok(HealthResponse {
+            status,
+            body: body.to_string(),
+        })
+    }
+}
+
+pub use imp::HealthClient;
+
+/// Health client shared by startup polling, `ping_backend` and the watchdog
+/// Built on first use and rebuilt when certificate checking changes; a failed build is not
+/// cached, so the next probe tries again
+#[derive(Default)]
+pub struct SharedHealthClient(Mutex<Option<(bool, Arc<HealthClient>)>>);
+
+/// The app-wide health client, building it on first use
+/// Invalid certificates are accepted only for `backend_tls = "self_signed"` on a loopback backend,
+/// so the check can never be fooled by another machine on the network
+pub fn shared(app: &tauri::AppHandle) -> Result<Arc<HealthClient>, String> {
+    let self_signed =
+        crate::config::current(app).backend_tls == crate::config::BackendTls::SelfSigned;
+    let accept_invalid_certs = self_signed && crate::health_host(app).is_loopback();
+    let state = app
+        .try_state::<SharedHealthClient>()
+        .ok_or("Health client is not initialized")?;
+    let mut cached = state.0.lock().map_err(|e| e.to_string())?;
+    if let Some((accepts, client)) = cached.as_ref()
+        && *accepts == accept_invalid_certs
+    {
+        return Ok(Arc::clone(client));
+    }
+    if self_signed && !accept_invalid_certs {
+        log::warn!(
+            "backend_tls = \"self_signed\" is only honoured for a loopback backend, checking certificates"
+        );
+    }
+    let client = Arc::new(HealthClient::build(accept_invalid_certs)?);
+    *cached = Some((accept_invalid_certs, Arc::clone(&client)));
+    Ok(client)
+}
+
+#[cfg(all(test, any(not(feature = "reqwest-health"), feature = "minimal-health")))]
+mod tests {
+    use super::imp::parse_response;
+
+    #[test]
+    fn ok_response_yields_status_and_body() {
+        let raw = b"HTTP/1.0 200 OK\r\nContent-Type: application/json\r\n\r\n{\"status\":\"ok\"}";
+        let response = parse_response(raw).unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, "{\"status\":\"ok\"}");
+    }
+
+    #[test]
+    fn non_200_status_is_passed_through() {
+        let response =
+            parse_response(b"HTTP/1.1 503 Service Unavailable\r\nServer: test\r\n\r\n").unwrap();
+        assert_eq!(response.status, 503);
+        assert_eq!(response.body, "");
+    }
+
+    #[test]
+    fn truncated_responses_are_rejected() {
+        assert!(parse_response(b"").is_err());
+        assert!(parse_response(b"HTTP/1.0 200 OK\r\nContent-Ty").is_err());
+        let err = parse_response(b"HTTP/1.0 200 OK\r\nContent-Length: 15\r\n\r\n{\"status\"")
+            .unwrap_err();
+        assert!(err.contains("truncated"), "{}", err);
+        assert!(parse_response(b"garbage\r\n\r\n").is_err());
+    }
+
+    #[test]
+    fn header_names_are_matched_case_insensitively() {
+        let raw = b"HTTP/1.0 200 OK\r\ncontent-LENGTH: 2\r\n\r\nokTRAILING";
+        assert_eq!(parse_response(raw).unwrap().body, "ok");
+        let raw = b"HTTP/1.0 200 OK\r\nCONTENT-LENGTH: 10\r\n\r\nok";
+        assert!(parse_response(raw).is_err());
+    }
+}
@@ -0,0 +1,88 @@
+use crate::config::Config;
+use log::{info, warn};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// File in the app data directory holding the last known-good startup settings
+const SNAPSHOT_FILE: &str = "last_good_config.json";
+
+/// Settings the backend last started with and answered health checks
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct KnownGood {
+    pub config: Config,
+    pub port: u16,
+    /// Backend executable that was used; `None` when the Python source tree ran
+    pub backend_executable: Option<PathBuf>,
+    /// Unix time of the startup in milliseconds
+    pub saved_at_ms: u64,
+}
+
+fn snapshot_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    crate::storage::app_data_dir(app).map(|dir| dir.join(SNAPSHOT_FILE))
+}
+
+/// Save the current settings once the just-started backend passes a health check
+/// Runs in the background; a backend that never becomes healthy leaves the old snapshot alone
+pub fn save_when_healthy(app: &tauri::AppHandle) {
+    let app = app.clone();
+    std::thread::spawn(move || {
+        if !crate::wait_for_health(&app, crate::health::startup_params(&app).startup_timeout) {
+            return;
+        }
+        if let Err(e) = save(&app) {
+            warn!("Could not save the known-good startup settings: {}", e);
+        }
+    });
+}
+
+fn save(app: &tauri::AppHandle) -> Result<(), String> {
+    let path = snapshot_path(app)?;
+    let backend_executable = match crate::diagnostics::startup_info(app).backend_mode {
+        Some(crate::diagnostics::BackendMode::Bundled { executable }) => Some(executable),
+        _ => None,
+    };
+    let snapshot = KnownGood {
+        config: crate::config::current(app),
+        port: crate::port::current_port(app),
+        backend_executable,
+        saved_at_ms: SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_millis() as u64)
+            .unwrap_or(0),
+    };
+    let contents = serde_json::to_string_pretty(&snapshot).map_err(|e| e.to_string())?;
+    std::fs::write(&path, contents).map_err(|e| format!("Failed to write {:?}: {}", path, e))
+}
+
+/// The last known-good settings, if a healthy startup was ever recorded
+pub fn load(app: &tauri::AppHandle) -> Result<Option<KnownGood>, String> {
+    let path = snapshot_path(app)?;
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => serde_json::from_str(&contents)
+            .map(Some)
+            .map_err(|e| format!("Invalid {:?}: {}", path, e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(format!("Failed to read {:?}: {}", path, e)),
+    }
+}
+
+/// Put the known-good settings back in place and restart the backend with them
+pub fn restore(app: &tauri::AppHandle) -> Result<KnownGood, String> {
+    let snapshot = load(app)?.ok_or("No known-good startup settings have been saved yet")?;
+    info!(
+        "Restoring the startup settings saved at {} (port {})",
+        snapshot.saved_at_ms, snapshot.port
+    );
+    crate::config::update(app, |config| *config = snapshot.config.clone())?;
+    crate::logging::apply_config_level(&snapshot.config.log_level);
+    crate::port::set_current_port(app, snapshot.port);
+    let data_dir = crate::storage::app_data_dir(app)?;
+    crate::set_active_db_path(
+        app,
+        crate::profiles::resolve_db_path(&data_dir, &snapshot.config),
+    );
+    crate::events::emit(app, "backend://port_changed", snapshot.port);
+    crate::restart_backend(app)?;
+    Ok(snapshot)
+}
@@ -1,74 +1,78 @@
 use log::{debug, error, info, warn};
-use std::io::Read;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::{Path, PathBuf};
 use std::process::{Child, Command, Stdio};
 use std::sync::Mutex;
 use tauri::Manager;
 
+mod app_lock;
+mod backups;
+mod clean_session;
+mod commands;
+mod config;
+mod database;
+mod diagnostics;
+mod error;
+mod events;
+mod health;
+mod health_client;
+mod known_good;
+mod logging;
+mod management;
+mod manifest;
+mod migrations;
+mod output;
+mod port;
+mod process;
+mod profiles;
+mod restarts;
+mod resume;
+mod secret;
+mod setup;
+mod shutdown;
+mod signing;
+mod storage;
+mod support;
+mod suspend;
+mod temp_cleanup;
+mod timing;
+mod wal;
+mod watchdog;
+
+use diagnostics::{BackendMode, DiscoveryReport, StartupInfo, record_startup_info};
+use error::BackendError;
+use migrations::{MigrationPhase, MigrationTracker};
+use port::{BackendPort, DEFAULT_BACKEND_PORT};
+use timing::{CpuScope, PhaseTimer, StartupTimeline};
+
+/// File name of the bundled backend executable
 #[cfg(windows)]
-use std::os::windows::process::CommandExt;
-
-/// Kill any process using the specified port (useful for cleaning up orphaned backend processes)
-fn kill_process_on_port(port: u16) {
-    info!("Checking for existing processes on port {}", port);
-
-    #[cfg(not(windows))]
-    {
-        // On Linux/macOS, use lsof to find and kill processes on the port
-        let output = Command::new("lsof")
-            .args(["-ti", &format!(":{}", port)])
-            .output();
-
-        if let Ok(output) = output {
-            let pids = String::from_utf8_lossy(&output.stdout);
-            for pid in pids.lines() {
-                if !pid.trim().is_empty() {
-                    info!("Killing existing process {} on port {}", pid.trim(), port);
-                    let _ = Command::new("kill").args(["-9", pid.trim()]).output();
-                }
-            }
-        }
-    }
-
-    #[cfg(windows)]
-    {
-        // On Windows, use netstat to find and taskkill to kill processes on the port
-        let output = Command::new("netstat").args(&["-ano"]).output();
-
-        if let Ok(output) = output {
-            let output_str = String::from_utf8_lossy(&output.stdout);
-            let port_str = format!(":{}", port);
-            for line in output_str.lines() {
-                if line.contains(&port_str) && line.contains("LISTENING") {
-                    // Extract PID from the last column
-                    if let Some(pid) = line.split_whitespace().last() {
-                        info!("Killing existing process {} on port {}", pid, port);
-                        let _ = Command::new("taskkill").args(&["/F", "/PID", pid]).output();
-                    }
-                }
-            }
-        }
-    }
-
-    // Give processes a moment to terminate
-    std::thread::sleep(std::time::Duration::from_millis(500));
-    debug!("Port cleanup completed");
-}
-
-/// Kill the backend process immediately without blocking
-/// On Windows, this kills the entire process tree (including child processes)
-/// This function returns immediately after initiating the kill, cleanup happens in background
+const BACKEND_EXE_NAME: &str = "backend-server.exe";
+#[cfg(not(windows))]
+const BACKEND_EXE_NAME: &str = "backend-server";
+
+/// Stop the backend process
+/// The backend is first asked to shut down (CTRL_BREAK on Windows, SIGTERM to its process group on
+/// Unix) and given a moment to exit, then the entire process tree (including child processes) is killed
+/// Once the kill is initiated the function returns, cleanup happens in background
 fn kill_backend_process(child: &mut Child) {
     let pid = child.id();
     info!("Stopping backend server (PID: {:?})...", pid);
 
-    // On Windows, use taskkill to kill the entire process tree immediately
+    // On Windows, ask the backend to shut down cleanly first so Django can close the database,
+    // then use taskkill to kill the entire process tree
     // This is more reliable than just killing the parent process
     #[cfg(windows)]
     {
+        if shutdown::send_ctrl_break(pid)
+            && shutdown::wait_for_exit(child, shutdown::GRACEFUL_SHUTDOWN_TIMEOUT)
+        {
+            info!("Backend server shut down gracefully");
+            return;
+        }
         info!("Killing process tree on Windows using taskkill");
         // Spawn taskkill without waiting - let it run in background
-        let _ = Command::new("taskkill")
+        let _ = process::configure_command(&mut Command::new("taskkill"))
             .args(&["/F", "/T", "/PID", &pid.to_string()])
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
@@ -76,6 +80,22 @@ fn kill_backend_process(child: &mut Child) {
         // Don't wait for taskkill to complete - return immediately
     }
 
+    // On Unix the backend leads its own process group, so signal the whole group to also reach
+    // children such as runserver's autoreloader that `child.kill()` would miss
+    #[cfg(unix)]
+    {
+        if shutdown::signal_group(pid, libc::SIGTERM)
+            && shutdown::wait_for_exit(child, shutdown::GRACEFUL_SHUTDOWN_TIMEOUT)
+        {
+            // Don't leave stragglers holding the port once the group leader is gone
+            shutdown::signal_group(pid, libc::SIGKILL);
+            info!("Backend server shut down gracefully");
+            return;
+        }
+        info!("Killing backend process group {}", pid);
+        shutdown::signal_group(pid, libc::SIGKILL);
+    }
+
     // Try to kill the process directly (fallback or non-Windows)
     // This is non-blocking
     if let Err(e) = child.kill() {
@@ -92,7 +112,7 @@ fn kill_backend_process(child: &mut Child) {
         #[cfg(windows)]
         {
             // On Windows, check if process still exists using tasklist
-            let output = Command::new("tasklist")
+            let output = process::configure_command(&mut Command::new("tasklist"))
                 .args(&["/FI", &format!("PID eq {}", pid_for_cleanup)])
                 .stdout(std::process::Stdio::null())
                 .stderr(std::process::Stdio::null())
@@ -106,7 +126,7 @@ fn kill_backend_process(child: &mut Child) {
                         pid_for_cleanup
                     );
                     // Try one more time with taskkill
-                    let _ = Command::new("taskkill")
+                    let _ = process::configure_command(&mut Command::new("taskkill"))
                         .args(&["/F", "/T", "/PID", &pid_for_cleanup.to_string()])
                         .stdout(std::process::Stdio::null())
                         .stderr(std::process::Stdio::null())
@@ -120,18 +140,14 @@ fn kill_backend_process(child: &mut Child) {
             }
         }
 
-        #[cfg(not(windows))]
+        #[cfg(unix)]
         {
-            // On Linux/macOS, use kill -9 to forcefully terminate
+            // On Linux/macOS, SIGKILL the whole process group to forcefully terminate
             info!(
-                "Forcefully killing backend process {} on Linux/macOS",
+                "Forcefully killing backend process group {} on Linux/macOS",
                 pid_for_cleanup
             );
-            let _ = Command::new("kill")
-                .args(["-9", &pid_for_cleanup.to_string()])
-                .stdout(std::process::Stdio::null())
-                .stderr(std::process::Stdio::null())
-                .output();
+            shutdown::signal_group(pid_for_cleanup, libc::SIGKILL);
             info!("Backend server cleanup initiated");
         }
     });
@@ -139,32 +155,313 @@ fn kill_backend_process(child: &mut Child) {
     info!("Backend server kill initiated (cleanup in background)");
 }
 
-/// Initialize the database by checking if it exists and running migrations if needed
-fn initialize_database(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error::Error>> {
-    // Get the app data directory
-    let app_data_dir = app.path().app_data_dir()?;
-    std::fs::create_dir_all(&app_data_dir)?;
+/// Upper bound for the delay between health checks
+const HEALTH_POLL_MAX: std::time::Duration = std::time::Duration::from_secs(1);
 
-    // Database path in app data directory
-    let db_path = app_data_dir.join("db.sqlite3");
-    let db_exists = db_path.exists();
+/// How long a stalled backend gets to answer again before it is restarted
+const RECONNECT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(5);
 
-    info!("Database path: {:?}", db_path);
-    info!("Database exists: {}", db_exists);
+/// Log an unexpected backend exit and emit `backend://failed` with its exit code and signal
+/// Returns the error message for the caller to propagate
+fn report_backend_exit(
+    app: &tauri::AppHandle,
+    context: &str,
+    status: std::process::ExitStatus,
+    stderr: &output::StderrTail,
+) -> String {
+    let exit = shutdown::ExitInfo::from(status);
+    let stderr = stderr.collect(STDERR_DRAIN_TIMEOUT);
+    let mut message = format!("Backend server {} ({})", context, exit);
+    if !stderr.is_empty() {
+        message = format!("{}:\n{}", message, stderr);
+    }
+    error!("{}", message);
+    events::emit(
+        app,
+        "backend://failed",
+        serde_json::json!({
+            "message": message,
+            "code": exit.code,
+            "signal": exit.signal,
+            "stderr": stderr,
+        }),
+    );
+    message
+}
 
-    if db_exists {
-        info!("Database already exists, skipping initialization");
+/// How long a fresh backend may run before it is checked for having exited immediately
+/// PyInstaller onefile bundles re-exec themselves, which a check right after spawn can race
+const SPAWN_GRACE_PERIOD: std::time::Duration = std::time::Duration::from_millis(300);
+
+/// How long to wait for an exited backend's last stderr output to be read
+const STDERR_DRAIN_TIMEOUT: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// Spawn the backend, retrying failures (e.g. an executable still locked by a virus scanner or on a
+/// flaky network drive) `startup_max_retries` times with backoff doubling from `startup_backoff_base_ms`
+fn spawn_with_retry(app: &tauri::AppHandle, cmd: &mut Command) -> std::io::Result<Child> {
+    let config = config::current(app);
+    let max_retries = if fail_fast() {
+        0
+    } else {
+        config.startup_max_retries
+    };
+    let mut delay = std::time::Duration::from_millis(config.startup_backoff_base_ms);
+    let command_line = process::shell_command_line(cmd);
+    info!("Backend command: {}", command_line);
+    record_startup_info(app, |info| info.backend_command = Some(command_line));
+    let mut retry = 0;
+    loop {
+        match cmd.spawn() {
+            Ok(child) => return Ok(child),
+            Err(e) if retry < max_retries => {
+                retry += 1;
+                restarts::record(app, restarts::RestartReason::SpawnRetry);
+                warn!(
+                    "Failed to spawn the backend: {}, retrying in {:?} ({}/{})",
+                    e, delay, retry, max_retries
+                );
+                std::thread::sleep(delay);
+                delay *= 2;
+            }
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Fail if a just-spawned backend is no longer running after the grace period
+fn check_spawned(
+    app: &tauri::AppHandle,
+    child: &mut Child,
+    stderr: &output::StderrTail,
+) -> Result<(), String> {
+    std::thread::sleep(SPAWN_GRACE_PERIOD);
+    match child.try_wait() {
+        Ok(Some(status)) => Err(report_backend_exit(
+            app,
+            "exited immediately",
+            status,
+            stderr,
+        )),
+        Ok(None) => {
+            info!("Backend server process is running");
+            Ok(())
+        }
+        Err(e) => {
+            let error_msg = format!("Error checking backend server status: {}", e);
+            error!("{}", error_msg);
+            Err(error_msg)
+        }
+    }
+}
+
+/// Address health checks go to: the backend's host, or loopback for a backend bound to all interfaces
+fn health_host(app: &tauri::AppHandle) -> IpAddr {
+    match backend_host(app) {
+        IpAddr::V4(v4) if v4.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+        IpAddr::V6(v6) if v6.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+        host => host,
+    }
+}
+
+/// URL scheme the backend is served with: `https` when `backend_tls` is set
+fn backend_scheme(app: &tauri::AppHandle) -> &'static str {
+    match config::current(app).backend_tls {
+        config::BackendTls::Off => "http",
+        config::BackendTls::On | config::BackendTls::SelfSigned => "https",
+    }
+}
+
+/// Health endpoint of the backend listening on `port`, over HTTPS when `backend_tls` is set
+fn health_url(app: &tauri::AppHandle, port: u16) -> String {
+    format!(
+        "{}://{}/api/budgets/health/",
+        backend_scheme(app),
+        SocketAddr::new(health_host(app), port)
+    )
+}
+
+/// Evaluate a health response against the configured success criteria
+/// By default any 2xx status passes; `health_statuses` and `health_body_contains` narrow that down
+fn check_health_response(
+    response: health_client::HealthResponse,
+    config: &config::Config,
+) -> Result<(), String> {
+    let status = response.status;
+    let status_ok = if config.health_statuses.is_empty() {
+        (200..300).contains(&status)
+    } else {
+        config.health_statuses.contains(&status)
+    };
+    if !status_ok {
+        return Err(format!("unexpected status {}", status));
+    }
+
+    if !config.health_body_contains.is_empty()
+        && !response.body.contains(&config.health_body_contains)
+    {
+        return Err(format!(
+            "body does not contain {:?}",
+            config.health_body_contains
+        ));
+    }
+    Ok(())
+}
+
+/// Send one health probe and record its outcome for `get_last_health`
+fn probe_health(
+    app: &tauri::AppHandle,
+    client: &health_client::HealthClient,
+    url: &str,
+    config: &config::Config,
+) -> Result<u64, String> {
+    let start = std::time::Instant::now();
+    let response = client.get(url, health::startup_params(app).request_timeout);
+    let latency_ms = start.elapsed().as_millis() as u64;
+    let (status, result) = match response {
+        Ok(response) => (
+            Some(response.status),
+            check_health_response(response, config),
+        ),
+        Err(e) => (None, Err(format!("no response: {}", e))),
+    };
+    health::record(app, latency_ms, status, &result);
+    result.map(|()| latency_ms)
+}
+
+/// Poll the running backend's health endpoint until it passes or `timeout` elapses
+fn wait_for_health(app: &tauri::AppHandle, timeout: std::time::Duration) -> bool {
+    let deadline = std::time::Instant::now() + timeout;
+    wait_for_health_until(app, || deadline)
+}
+
+/// Poll the health endpoint until it passes or the deadline, re-read before every poll, passes
+fn wait_for_health_until(
+    app: &tauri::AppHandle,
+    deadline: impl Fn() -> std::time::Instant,
+) -> bool {
+    let client = match health_client::shared(app) {
+        Ok(client) => client,
+        Err(e) => {
+            warn!("Could not build health check client: {}", e);
+            return false;
+        }
+    };
+    let url = health_url(app, port::current_port(app));
+    let health_config = config::current(app);
+    let mut poll_interval = health::startup_params(app).poll_interval;
+    loop {
+        match probe_health(app, &client, &url, &health_config) {
+            Ok(_) => return true,
+            Err(reason) => debug!("Health check not passed: {}", reason),
+        }
+        let remaining = deadline().saturating_duration_since(std::time::Instant::now());
+        if remaining.is_zero() {
+            return false;
+        }
+        std::thread::sleep(poll_interval.min(remaining));
+        poll_interval = (poll_interval * 2).min(HEALTH_POLL_MAX);
+    }
+}
+
+/// Check that the backend still answers (e.g. after sleep/resume) and restart it if it doesn't
+fn reconnect_backend(app: &tauri::AppHandle) -> Result<(), String> {
+    if wait_for_health(app, RECONNECT_TIMEOUT) {
+        info!("Backend is responsive");
         return Ok(());
     }
+    warn!(
+        "Backend did not respond within {:?}, restarting it",
+        RECONNECT_TIMEOUT
+    );
+    restart_backend_for(app, restarts::RestartReason::Reconnect)
+}
 
-    info!("Database not found, initializing...");
+/// Whether discovery may use `path` under the configured `discovery_roots`
+/// Without roots every path is allowed; with roots only paths inside them or inside the bundled
+/// resources are, compared after resolving symlinks and `..` (paths that don't exist are dropped)
+fn discovery_allowed(app: &tauri::AppHandle, path: &Path) -> bool {
+    let roots = config::current(app).discovery_roots;
+    if roots.is_empty() {
+        return true;
+    }
+    let Ok(path) = path.canonicalize() else {
+        return false;
+    };
+    roots
+        .into_iter()
+        .chain(app.path().resource_dir().ok())
+        .filter_map(|root| root.canonicalize().ok())
+        .any(|root| path.starts_with(root))
+}
 
-    // Try to find backend directory
-    let exe_path = std::env::current_exe()?;
+/// Working directory for a backend executable found outside the app bundle
+/// Prefers the backend source tree it was built from (`backend/dist/backend-server` ->
+/// `backend`), recognised by `manage.py` or `requirements.txt`; otherwise the executable's own
+/// directory. Never `.`, which would depend on where the app happened to be started
+fn backend_dir_for_executable(exe_path: &Path) -> PathBuf {
     let exe_dir = exe_path
         .parent()
-        .ok_or("Could not get executable directory")?;
+        .filter(|dir| !dir.as_os_str().is_empty())
+        .map(Path::to_path_buf)
+        .or_else(|| {
+            exe_path
+                .canonicalize()
+                .ok()?
+                .parent()
+                .map(Path::to_path_buf)
+        })
+        .unwrap_or_else(|| exe_path.to_path_buf());
+    exe_dir
+        .ancestors()
+        .take(2)
+        .find(|dir| dir.join("manage.py").is_file() || dir.join("requirements.txt").is_file())
+        .map(Path::to_path_buf)
+        .unwrap_or(exe_dir)
+}
+
+/// Opt-in for CI: no spawn retries, short health timeouts, no automatic restarts, and startup
+/// problems that are normally tolerated are returned as errors
+const FAIL_FAST_ENV: &str = "BUDGET_FAIL_FAST";
+
+/// Whether `BUDGET_FAIL_FAST=1` is set, read once per run
+fn fail_fast() -> bool {
+    static FAIL_FAST: std::sync::OnceLock<bool> = std::sync::OnceLock::new();
+    *FAIL_FAST.get_or_init(|| std::env::var(FAIL_FAST_ENV).ok().as_deref() == Some("1"))
+}
+
+/// Opt-in that lets QA point `BACKEND_SERVER_PATH` at a small fake backend
+const TRUST_BACKEND_EXE_ENV: &str = "BUDGET_TRUST_BACKEND_EXE";
+
+/// Backend executable exempt from the placeholder checks, when `BUDGET_TRUST_BACKEND_EXE=1`
+/// is set together with `BACKEND_SERVER_PATH`; meant for tests, never for production
+fn trusted_backend_exe() -> Option<&'static Path> {
+    static TRUSTED: std::sync::OnceLock<Option<PathBuf>> = std::sync::OnceLock::new();
+    TRUSTED
+        .get_or_init(|| {
+            if std::env::var(TRUST_BACKEND_EXE_ENV).ok().as_deref() != Some("1") {
+                return None;
+            }
+            let Some(path) = std::env::var_os("BACKEND_SERVER_PATH").map(PathBuf::from) else {
+                warn!(
+                    "{}=1 is set without BACKEND_SERVER_PATH, ignoring it",
+                    TRUST_BACKEND_EXE_ENV
+                );
+                return None;
+            };
+            warn!("==============================================================");
+            warn!(
+                "{}=1: trusting {:?} without validation",
+                TRUST_BACKEND_EXE_ENV, path
+            );
+            warn!("This is for testing only and must never be set in production");
+            warn!("==============================================================");
+            Some(path)
+        })
+        .as_deref()
+}
 
+/// Find a backend source tree (a directory with `manage.py`) near the executable
+fn find_backend_source_dir(exe_dir: &Path) -> Option<PathBuf> {
     // Try multiple possible backend locations
     let mut possible_backend_paths: Vec<PathBuf> = vec![
         // Relative to executable (for bundled app)
@@ -181,66 +478,124 @@ fn initialize_database(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
         }
     }
 
-    let mut backend_path: Option<PathBuf> = None;
     for path in &possible_backend_paths {
         let manage_py = path.join("manage.py");
         if manage_py.exists() {
-            backend_path = Some(path.clone());
             info!("Found backend at: {:?}", path);
-            break;
+            return Some(path.clone());
+        }
+    }
+
+    warn!(
+        "Backend directory not found in any of these locations: {:?}",
+        possible_backend_paths
+    );
+    None
+}
+
+/// Python interpreter for a backend source tree: its `.venv` if present, else `python3`/`python` from PATH
+fn find_python(backend_path: &Path) -> Option<PathBuf> {
+    // Check Windows path first (Scripts/python.exe)
+    let venv_python_windows = backend_path
+        .join(".venv")
+        .join("Scripts")
+        .join("python.exe");
+    // Check Unix path (bin/python)
+    let venv_python_unix = backend_path.join(".venv").join("bin").join("python");
+
+    if venv_python_windows.exists() {
+        info!(
+            "Using virtual environment Python (Windows): {:?}",
+            venv_python_windows
+        );
+        Some(venv_python_windows)
+    } else if venv_python_unix.exists() {
+        info!(
+            "Using virtual environment Python (Unix): {:?}",
+            venv_python_unix
+        );
+        Some(venv_python_unix)
+    } else {
+        // Try python3, then python - use fast check to avoid hanging
+        let check_python = |cmd: &str| -> bool {
+            process::configure_command(&mut Command::new(cmd))
+                .arg("--version")
+                .stdout(std::process::Stdio::null())
+                .stderr(std::process::Stdio::null())
+                .output()
+                .is_ok()
+        };
+
+        if check_python("python3") {
+            Some(PathBuf::from("python3"))
+        } else if check_python("python") {
+            Some(PathBuf::from("python"))
+        } else {
+            None
+        }
+    }
+}
+
+/// Initialize the database by checking if it exists and running migrations if needed
+/// Filesystem calls are bounded so an unreachable network mount fails with `StorageUnavailable`
+fn initialize_database(db_path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+    // Ensure the directory holding the active profile's database exists
+    if let Some(parent) = db_path.parent() {
+        storage::create_dir_all(parent)?;
+    }
+
+    let db_path = db_path.to_path_buf();
+    let db_exists = storage::exists(&db_path)?;
+    // A placeholder left by the fallback below is an empty file, not a database to keep
+    let db_valid = db_exists && {
+        let owned = db_path.clone();
+        storage::with_timeout("read header", &db_path, move || {
+            database::has_sqlite_header(&owned)
+        })?
+    };
+
+    info!("Database path: {:?}", db_path);
+    info!("Database exists: {} (valid: {})", db_exists, db_valid);
+
+    if db_valid {
+        // Recovery only goes ahead when it can lock the database, which a backend still using
+        // it (or still exiting) prevents
+        if let Err(e) = wal::recover_orphaned(&db_path) {
+            warn!("Could not recover leftover WAL files: {}", e);
         }
+        info!("Database already exists, skipping initialization");
+        return Ok(());
+    }
+
+    if db_exists {
+        warn!(
+            "{:?} is empty or not an SQLite database, initializing it",
+            db_path
+        );
+    } else {
+        info!("Database not found, initializing...");
     }
 
+    // Try to find backend directory
+    let exe_path = std::env::current_exe()?;
+    let exe_dir = exe_path
+        .parent()
+        .ok_or("Could not get executable directory")?;
+
+    let backend_path = find_backend_source_dir(exe_dir);
+
     if let Some(backend_path) = backend_path {
         info!("Initializing database at: {:?}", db_path);
 
         // Ensure database directory exists
         if let Some(parent) = db_path.parent() {
-            std::fs::create_dir_all(parent)?;
+            storage::create_dir_all(parent)?;
         }
 
         // Try to find Python in virtual environment first, then system Python
-        let python_cmd = {
-            // Check Windows path first (Scripts/python.exe)
-            let venv_python_windows = backend_path
-                .join(".venv")
-                .join("Scripts")
-                .join("python.exe");
-            // Check Unix path (bin/python)
-            let venv_python_unix = backend_path.join(".venv").join("bin").join("python");
-
-            if venv_python_windows.exists() {
-                info!(
-                    "Using virtual environment Python (Windows): {:?}",
-                    venv_python_windows
-                );
-                venv_python_windows
-            } else if venv_python_unix.exists() {
-                info!(
-                    "Using virtual environment Python (Unix): {:?}",
-                    venv_python_unix
-                );
-                venv_python_unix
-            } else {
-                // Try python3, then python - use fast check to avoid hanging
-                let check_python = |cmd: &str| -> bool {
-                    Command::new(cmd)
-                        .arg("--version")
-                        .stdout(std::process::Stdio::null())
-                        .stderr(std::process::Stdio::null())
-                        .output()
-                        .is_ok()
-                };
-
-                if check_python("python3") {
-                    PathBuf::from("python3")
-                } else if check_python("python") {
-                    PathBuf::from("python")
-                } else {
-                    warn!("Python not found, cannot run migrations");
-                    return Ok(()); // Don't fail, database will be created on first use
-                }
-            }
+        let Some(python_cmd) = find_python(&backend_path) else {
+            warn!("Python not found, cannot run migrations");
+            return Ok(()); // Don't fail, database will be created on first use
         };
 
         let mut cmd = Command::new(&python_cmd);
@@ -252,11 +607,8 @@ fn initialize_database(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
         cmd.env("DJANGO_SETTINGS_MODULE", "config.settings");
 
         // Hide console window on Windows (but keep output capture for .output())
-        #[cfg(windows)]
-        {
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            cmd.creation_flags(CREATE_NO_WINDOW);
-        }
+        process::configure_command(&mut cmd);
+        process::use_c_locale(&mut cmd);
 
         info!("Running migrations with command: {:?}", cmd);
 
@@ -288,21 +640,17 @@ fn initialize_database(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
             }
         }
     } else {
-        warn!(
-            "Backend directory not found in any of these locations: {:?}",
-            possible_backend_paths
-        );
         warn!("Database will be created on first use when backend is available.");
 
         // For packaged apps, create an empty database file so the directory structure is correct
         // The backend executable will handle migrations when it starts
         if let Some(parent) = db_path.parent() {
-            if let Err(e) = std::fs::create_dir_all(parent) {
+            if let Err(e) = storage::create_dir_all(parent) {
                 warn!("Failed to create database directory: {}", e);
             } else {
                 // Create an empty database file - SQLite will initialize it properly when first accessed
-                if !db_path.exists() {
-                    if let Err(e) = std::fs::File::create(&db_path) {
+                if !storage::exists(&db_path)? {
+                    if let Err(e) = storage::create_file(&db_path) {
                         warn!("Failed to create database file: {}", e);
                     } else {
                         info!("Created empty database file at: {:?}", db_path);
@@ -315,6 +663,37 @@ fn initialize_database(app: &tauri::AppHandle) -> Result<(), Box<dyn std::error:
     Ok(())
 }
 
+/// Restore a stripped requirements.txt from the copy bundled with the app; returns whether it did
+fn restore_bundled_requirements(app: &tauri::AppHandle, destination: &Path) -> bool {
+    let Ok(resource_dir) = app.path().resource_dir() else {
+        return false;
+    };
+    let Some(bundled) = [
+        resource_dir.join("resources").join("requirements.txt"),
+        resource_dir.join("requirements.txt"),
+    ]
+    .into_iter()
+    .find(|path| path.is_file()) else {
+        return false;
+    };
+    match std::fs::copy(&bundled, destination) {
+        Ok(_) => {
+            warn!(
+                "requirements.txt was missing, restored it from the bundled copy {:?}",
+                bundled
+            );
+            true
+        }
+        Err(e) => {
+            warn!(
+                "Could not restore requirements.txt from {:?}: {}",
+                bundled, e
+            );
+            false
+        }
+    }
+}
+
 /// Check if Python dependencies are installed in virtual environment
 /// Returns true if Django can be imported
 fn check_backend_dependencies(python_cmd: &PathBuf) -> bool {
@@ -324,11 +703,7 @@ fn check_backend_dependencies(python_cmd: &PathBuf) -> bool {
     check_cmd.stdout(Stdio::null());
     check_cmd.stderr(Stdio::null());
 
-    #[cfg(windows)]
-    {
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        check_cmd.creation_flags(CREATE_NO_WINDOW);
-    }
+    process::configure_command(&mut check_cmd);
 
     check_cmd
         .output()
@@ -338,8 +713,14 @@ fn check_backend_dependencies(python_cmd: &PathBuf) -> bool {
 
 /// Setup backend virtual environment and install dependencies
 /// Returns true if setup was successful
-fn setup_backend_dependencies(backend_path: &Path, python_cmd: &Path) -> bool {
+/// The venv and pip steps can be cancelled with `setup::cancel`
+fn setup_backend_dependencies(
+    app: &tauri::AppHandle,
+    backend_path: &Path,
+    python_cmd: &Path,
+) -> bool {
     info!("Setting up backend dependencies...");
+    setup::begin(app);
 
     // Check if virtual environment exists
     let venv_python_windows = backend_path
@@ -360,15 +741,18 @@ fn setup_backend_dependencies(backend_path: &Path, python_cmd: &Path) -> bool {
         venv_cmd.stdout(Stdio::null());
         venv_cmd.stderr(Stdio::null());
 
-        #[cfg(windows)]
-        {
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            venv_cmd.creation_flags(CREATE_NO_WINDOW);
-        }
+        process::configure_command(&mut venv_cmd);
 
-        if venv_cmd.output().is_err() {
-            warn!("Failed to create virtual environment");
-            return false;
+        match setup::run_step(app, &mut venv_cmd) {
+            Ok(true) => {}
+            Ok(false) => {
+                warn!("Failed to create virtual environment");
+                return false;
+            }
+            Err(e) => {
+                warn!("Failed to create virtual environment: {}", e);
+                return false;
+            }
         }
     }
 
@@ -385,7 +769,7 @@ fn setup_backend_dependencies(backend_path: &Path, python_cmd: &Path) -> bool {
     // Install dependencies
     info!("Installing Python dependencies...");
     let requirements_file = backend_path.join("requirements.txt");
-    if !requirements_file.exists() {
+    if !requirements_file.exists() && !restore_bundled_requirements(app, &requirements_file) {
         warn!("requirements.txt not found at {:?}", requirements_file);
         return false;
     }
@@ -400,22 +784,16 @@ fn setup_backend_dependencies(backend_path: &Path, python_cmd: &Path) -> bool {
     pip_cmd.stdout(Stdio::null());
     pip_cmd.stderr(Stdio::null());
 
-    #[cfg(windows)]
-    {
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        pip_cmd.creation_flags(CREATE_NO_WINDOW);
-    }
+    process::configure_command(&mut pip_cmd);
 
-    match pip_cmd.output() {
-        Ok(output) => {
-            if output.status.success() {
-                info!("Dependencies installed successfully");
-                true
-            } else {
-                let stderr = String::from_utf8_lossy(&output.stderr);
-                warn!("Failed to install dependencies: {}", stderr);
-                false
-            }
+    match setup::run_step(app, &mut pip_cmd) {
+        Ok(true) => {
+            info!("Dependencies installed successfully");
+            true
+        }
+        Ok(false) => {
+            warn!("Failed to install dependencies");
+            false
         }
         Err(e) => {
             warn!("Error installing dependencies: {}", e);
@@ -424,22 +802,97 @@ fn setup_backend_dependencies(backend_path: &Path, python_cmd: &Path) -> bool {
     }
 }
 
+/// Set environment variables derived from the user config on a backend server command
+fn apply_backend_env(app: &tauri::AppHandle, cmd: &mut Command) {
+    let config = config::current(app);
+    cmd.env("DJANGO_DEBUG", if config.debug { "True" } else { "False" });
+    if config.read_only {
+        cmd.env("DATABASE_READ_ONLY", "1");
+    }
+    // Per-install key instead of the insecure default in settings.py
+    match secret::load_or_create(app) {
+        Ok(key) => {
+            cmd.env("SECRET_KEY", key);
+        }
+        Err(e) => warn!(
+            "Could not load the secret key, using the backend default: {}",
+            e
+        ),
+    }
+    // Other devices reach the backend by this machine's LAN name/address, which Django must accept
+    if !backend_host(app).is_loopback() {
+        cmd.env("ALLOWED_HOSTS", "*");
+    }
+}
+
+/// Address the backend binds to; loopback unless remote access was explicitly enabled
+/// The default IPv4 loopback is swapped for `::1` on systems where it is unavailable
+fn backend_host(app: &tauri::AppHandle) -> IpAddr {
+    match config::current(app).backend_host.parse() {
+        Ok(IpAddr::V4(v4)) if v4.is_loopback() => match port::default_loopback() {
+            IpAddr::V6(v6) => IpAddr::V6(v6),
+            IpAddr::V4(_) => IpAddr::V4(v4),
+        },
+        Ok(host) => host,
+        Err(_) => port::default_loopback(),
+    }
+}
+
 /// Start the Django backend server
 /// Returns immediately after spawning the process without blocking on server readiness
 /// The frontend will handle retries if the server isn't ready immediately
 ///
 /// This function first tries to use a bundled backend executable (from PyInstaller),
 /// and falls back to Python if the executable is not found.
+/// A `known_exe` the caller already discovered is used as is, and never falls back to Python.
 fn start_backend_server(
     app: &tauri::AppHandle,
     backend_path: &Path,
     db_path: &Path,
+    known_exe: Option<&Path>,
 ) -> Result<Child, Box<dyn std::error::Error>> {
     info!("Starting Django backend server...");
 
-    // Kill any existing process on port 8000 to avoid "port already in use" errors
+    // Kill orphaned backend processes on our port to avoid "port already in use" errors
     // This handles orphaned backend processes from previous app sessions
-    kill_process_on_port(8000);
+    // Unrelated processes are never killed here; the backend moves to the next free port instead
+    let mut port = port::current_port(app);
+    let port_timer = PhaseTimer::start("port_cleanup");
+    let cleanup = if config::current(app).manage_port {
+        port::kill_process_on_port(port)
+    } else {
+        // Port management is disabled: never kill anything, only check whether the port is free
+        Ok(port::is_port_free(port))
+    };
+    port_timer.finish(app);
+    match cleanup {
+        Ok(true) => {}
+        Ok(false) => {
+            // Only our own backends were on the port, but it was not released: move to a free port
+            // rather than failing the spawn with "address already in use"
+            warn!("Port {} is still in use", port);
+            port = port::move_to_next_free_port(app, port)?;
+        }
+        Err(BackendError::PortConflict { owner, .. }) => {
+            // Another application holds the port and is never killed here: move out of its way
+            warn!(
+                "Port {} is in use by {} (PID {})",
+                port, owner.name, owner.pid
+            );
+            // The conflicting port goes along, since the backend has moved off it by the time
+            // the user resolves the conflict
+            events::emit(
+                app,
+                "backend://port_conflict",
+                serde_json::json!({ "port": port, "owner": owner }),
+            );
+            port = port::move_to_next_free_port(app, port)?;
+        }
+        Err(e) => {
+            error!("{}", e);
+            return Err(e.into());
+        }
+    }
 
     // First, try to find bundled backend executable (PyInstaller bundle)
     // Check multiple possible locations:
@@ -452,17 +905,14 @@ fn start_backend_server(
     let exe_dir = exe_path.as_ref().and_then(|p| p.parent());
 
     // Build list of possible executable paths, prioritizing platform-specific executables
-    let mut possible_exe_paths: Vec<PathBuf> = vec![];
-
-    // Platform-specific executable name
-    #[cfg(windows)]
-    let backend_exe_name = "backend-server.exe";
-    #[cfg(not(windows))]
-    let backend_exe_name = "backend-server";
+    let mut possible_exe_paths: Vec<PathBuf> = trusted_backend_exe()
+        .map(Path::to_path_buf)
+        .into_iter()
+        .collect();
 
     // First priority: Check app data directory (where we install the backend)
-    if let Ok(app_data_dir) = app.path().app_data_dir() {
-        let installed_backend = app_data_dir.join(backend_exe_name);
+    if let Ok(app_data_dir) = storage::app_data_dir(app) {
+        let installed_backend = app_data_dir.join(BACKEND_EXE_NAME);
         info!(
             "Checking app data directory for backend: {:?}",
             installed_backend
@@ -472,7 +922,7 @@ fn start_backend_server(
 
     // Second priority: Check the backend_path passed to this function
     // This allows the caller to specify a custom location
-    possible_exe_paths.push(backend_path.join(backend_exe_name));
+    possible_exe_paths.push(backend_path.join(BACKEND_EXE_NAME));
 
     // Third priority: Check backend/dist for development builds
     #[cfg(windows)]
@@ -539,43 +989,65 @@ fn start_backend_server(
     }
 
     // Find the first existing executable, filtering out placeholders (very small files)
-    let backend_exe = possible_exe_paths
-        .iter()
-        .find(|p| {
-            if !p.exists() {
-                return false;
-            }
+    // In Python mode only a caller-provided executable is used, never a discovered one
+    let python_only = config::current(app).backend_mode == config::LaunchMode::Python;
+    let backend_exe = known_exe.map(Path::to_path_buf).or_else(|| {
+        if python_only {
+            return None;
+        }
+        possible_exe_paths
+            .iter()
+            .find(|p| {
+                if !p.exists() {
+                    return false;
+                }
 
-            // On non-Windows, skip .exe files (they're Windows executables)
-            #[cfg(not(windows))]
-            {
-                if p.file_name()
-                    .and_then(|n| n.to_str())
-                    .map(|s| s.ends_with(".exe"))
-                    .unwrap_or(false)
+                // On non-Windows, skip .exe files (they're Windows executables)
+                #[cfg(not(windows))]
                 {
-                    return false;
+                    if p.file_name()
+                        .and_then(|n| n.to_str())
+                        .map(|s| s.ends_with(".exe"))
+                        .unwrap_or(false)
+                    {
+                        return false;
+                    }
                 }
-            }
 
-            // Filter out placeholder files (very small files < 1KB are likely placeholders)
-            if let Ok(metadata) = std::fs::metadata(p) {
-                let size = metadata.len();
-                if size < 1024 {
-                    warn!(
-                        "Skipping potential placeholder file: {:?} (size: {} bytes)",
-                        p, size
-                    );
-                    return false;
+                // Filter out placeholder files (very small files < 1KB are likely placeholders)
+                if trusted_backend_exe() == Some(p.as_path()) {
+                    return true;
+                }
+                if let Ok(metadata) = std::fs::metadata(p) {
+                    let size = metadata.len();
+                    if size < 1024 {
+                        warn!(
+                            "Skipping potential placeholder file: {:?} (size: {} bytes)",
+                            p, size
+                        );
+                        return false;
+                    }
                 }
-            }
 
-            true
-        })
-        .cloned();
+                true
+            })
+            .cloned()
+    });
 
     if let Some(exe_path) = backend_exe {
         info!("Found bundled backend executable: {:?}", exe_path);
+        // A writable discovery path could hold a planted executable
+        if let Err(e) = signing::ensure_trusted(app, &exe_path) {
+            error!("{}", e);
+            return Err(e.into());
+        }
+        record_startup_info(app, |startup| {
+            startup.backend_mode = Some(BackendMode::Bundled {
+                executable: exe_path.clone(),
+            });
+            startup.python_version = None;
+            startup.database_path = Some(db_path.to_path_buf());
+        });
 
         // On Unix systems, ensure the executable has execute permissions
         #[cfg(not(windows))]
@@ -606,18 +1078,37 @@ fn start_backend_server(
         // Run migrations in background
         let exe_path_clone = exe_path.clone();
         let db_path_clone = db_path.to_path_buf();
+        let app_for_migrations = app.clone();
+        // Shared with the health poll so its deadline can follow migration progress
+        let migration_tracker = MigrationTracker::default();
+        let migration_tracker_thread = migration_tracker.clone();
+        let confirm_migrations = config::current(app).migrate_confirm;
+        let read_only = config::current(app).read_only;
+        if !confirm_migrations && !read_only {
+            migration_tracker.set(MigrationPhase::Running);
+        }
         std::thread::spawn(move || {
+            if read_only {
+                info!("Read-only mode, skipping database migrations");
+                return;
+            }
+            if confirm_migrations {
+                // Leave schema changes to the user: report pending migrations instead of applying them
+                migrations::notify_if_pending(&app_for_migrations);
+                return;
+            }
             info!("Running database migrations in background...");
+            let migrate_timer =
+                PhaseTimer::start_measured(&app_for_migrations, "migrations", CpuScope::Children);
+            // `--manage` runs migrate on its own and exits, without starting a server, so the
+            // exit code reflects the migration result
             let mut migrate_cmd = Command::new(&exe_path_clone);
-            migrate_cmd.arg("--migrate");
             migrate_cmd.arg("--database-path");
             migrate_cmd.arg(db_path_clone.to_string_lossy().to_string());
+            migrate_cmd.args(["--manage", "migrate", "--noinput"]);
 
-            #[cfg(windows)]
-            {
-                const CREATE_NO_WINDOW: u32 = 0x08000000;
-                migrate_cmd.creation_flags(CREATE_NO_WINDOW);
-            }
+            process::configure_command(&mut migrate_cmd);
+            process::use_c_locale(&mut migrate_cmd);
 
             // Capture output to see what's happening
             match migrate_cmd.output() {
@@ -625,191 +1116,160 @@ fn start_backend_server(
                     let stdout = String::from_utf8_lossy(&output.stdout);
                     let stderr = String::from_utf8_lossy(&output.stderr);
 
-                    // Check if migrations actually completed successfully by looking at stdout
-                    // Migrations can exit with code 1 due to autoreload issues, but still succeed
-                    let migrations_succeeded = stdout.contains("No migrations to apply")
-                        || stdout.contains("Running migrations:")
-                        || stdout.contains("Applying");
-
-                    if output.status.success() || migrations_succeeded {
+                    if output.status.success() {
                         info!("Database migrations completed successfully");
                         if !stdout.trim().is_empty() {
                             info!("Migration output: {}", stdout.trim());
                         }
+                        migrations::notify_done(&app_for_migrations, &stdout);
                     } else {
-                        // Only report as error if migrations actually failed
                         error!("Migration failed. Exit code: {:?}", output.status.code());
                         if !stderr.trim().is_empty() {
-                            // Check if it's just a port conflict (non-critical)
-                            if stderr.contains("port is already in use") {
-                                warn!("Migration warning (non-critical): {}", stderr.trim());
-                                info!("Migrations completed successfully despite port warning");
-                            } else {
-                                error!("Migration stderr: {}", stderr.trim());
-                            }
+                            error!("Migration stderr: {}", stderr.trim());
                         }
                         if !stdout.trim().is_empty() {
                             info!("Migration stdout: {}", stdout.trim());
                         }
-                        if !migrations_succeeded {
-                            warn!("Migrations may have failed, but server is running");
-                        }
+                        warn!("Migrations failed, but server is running");
                     }
                 }
                 Err(e) => {
                     warn!("Could not run migrations: {}. Server is running anyway.", e);
                 }
             }
+            migrate_timer.finish(&app_for_migrations);
+            migration_tracker_thread.set(MigrationPhase::Finished(std::time::Instant::now()));
         });
 
         // Start the server
         let mut cmd = Command::new(&exe_path);
         cmd.arg("--host");
-        cmd.arg("127.0.0.1");
+        cmd.arg(backend_host(app).to_string());
         cmd.arg("--port");
-        cmd.arg("8000");
+        cmd.arg(port.to_string());
         cmd.arg("--database-path");
         cmd.arg(db_path.to_string_lossy().to_string());
+        apply_backend_env(app, &mut cmd);
 
-        #[cfg(windows)]
-        {
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            cmd.creation_flags(CREATE_NO_WINDOW);
-        }
+        // Own process group/session so the backend's whole tree can be shut down
+        process::configure_backend_command(&mut cmd);
 
         // Capture stderr to a pipe so we can read errors if the server fails to start
         // We'll spawn a thread to read stderr in the background
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
 
-        let mut child = cmd.spawn()?;
-        info!("Backend server started with PID: {:?}", child.id());
+        // A misconfigured backend fails here with Django's own messages instead of exiting later
+        if config::current(app).preflight_check {
+            management::preflight(app)?;
+        }
 
-        // Spawn a thread to read stderr (Django logs HTTP requests to stderr)
-        let stderr_handle = child.stderr.take();
-        if let Some(mut stderr) = stderr_handle {
-            std::thread::spawn(move || {
-                let mut buffer = [0u8; 1024];
-                loop {
-                    match stderr.read(&mut buffer) {
-                        Ok(0) => break, // EOF
-                        Ok(n) => {
-                            let output = String::from_utf8_lossy(&buffer[..n]);
-                            if !output.trim().is_empty() {
-                                // Django logs HTTP requests to stderr - these are informational, not errors
-                                // Only log actual errors (containing "Error", "Exception", "Traceback")
-                                let trimmed = output.trim();
-                                if trimmed.contains("Error")
-                                    || trimmed.contains("Exception")
-                                    || trimmed.contains("Traceback")
-                                {
-                                    warn!("Backend: {}", trimmed);
-                                } else {
-                                    debug!("Backend: {}", trimmed);
-                                }
-                            }
-                        }
-                        Err(e) => {
-                            warn!("Error reading backend stderr: {}", e);
-                            break;
-                        }
-                    }
-                }
-            });
+        // The port can take a moment to be released after cleanup, so don't race the spawn against it
+        if !port::wait_until_free(port, port::PORT_RELEASE_TIMEOUT) {
+            let error_msg = format!("Port {} is still in use, cannot start the backend", port);
+            error!("{}", error_msg);
+            return Err(error_msg.into());
         }
 
+        let spawn_timer = PhaseTimer::start("spawn");
+        let mut child = spawn_with_retry(app, &mut cmd)?;
+        spawn_timer.finish(app);
+        info!("Backend server started with PID: {:?}", child.id());
+        diagnostics::record_spawn(app, child.id());
+
+        // Drain stdout and stderr (Django logs HTTP requests to stderr) so the pipes never fill up
+        let stderr = output::forward(app, &mut child);
+
         // Check if process started successfully
-        match child.try_wait() {
-            Ok(Some(status)) => {
-                // Process exited immediately - try to get stderr output
-                let error_msg = format!(
-                    "Backend server exited immediately with status: {:?}",
-                    status
-                );
-                error!("{}", error_msg);
-                return Err(error_msg.into());
-            }
-            Ok(None) => {
-                info!("Backend server process is running");
-            }
-            Err(e) => {
-                let error_msg = format!("Error checking backend server status: {}", e);
-                error!("{}", error_msg);
-                return Err(error_msg.into());
-            }
-        }
+        check_spawned(app, &mut child, &stderr)?;
 
         // Wait for backend to be ready by polling the health endpoint
-        // This is more reliable than a fixed delay
+        // This is more reliable than a fixed delay. The deadline adapts to migrations:
+        // it is extended while they run and shortened once they report completion
+        let health_timer = PhaseTimer::start("health_wait");
         let start_time = std::time::Instant::now();
-        let health_url = "http://127.0.0.1:8000/api/budgets/health/";
-        let max_wait = std::time::Duration::from_secs(30); // Maximum wait time
-        let poll_interval = std::time::Duration::from_millis(500); // Check every 500ms
+        let health_url = health_url(app, port);
+        // Start with quick probes for a fast-starting backend and back off to spare the logs
+        let startup_params = health::startup_params(app);
+        let mut poll_interval = startup_params.poll_interval;
 
         info!("Waiting for backend to be ready at {}...", health_url);
 
-        let client = reqwest::blocking::Client::builder()
-            .timeout(std::time::Duration::from_secs(2))
-            .build()
-            .unwrap_or_else(|_| reqwest::blocking::Client::new());
+        let client = health_client::shared(app)?;
+        let health_config = config::current(app);
 
         loop {
             // First check if process is still running
             match child.try_wait() {
                 Ok(Some(status)) => {
-                    let error_msg = format!(
-                        "Backend server exited during startup with status: {:?}",
-                        status
+                    return Err(
+                        report_backend_exit(app, "exited during startup", status, &stderr).into(),
                     );
-                    error!("{}", error_msg);
-                    return Err(error_msg.into());
                 }
                 Ok(None) => {
                     // Process still running, continue
                 }
+                Err(e) if fail_fast() => {
+                    return Err(format!("Error checking backend server status: {}", e).into());
+                }
                 Err(e) => {
                     warn!("Error checking backend server status: {}", e);
                 }
             }
 
             // Try health check
-            match client.get(health_url).send() {
-                Ok(response) => {
-                    if response.status().is_success() {
-                        let elapsed = start_time.elapsed();
-                        info!(
-                            "Backend is ready! Startup took {:.2}s",
-                            elapsed.as_secs_f64()
-                        );
-                        break;
-                    } else {
-                        debug!("Health check returned status: {}", response.status());
-                    }
+            match probe_health(app, &client, &health_url, &health_config) {
+                // Optionally hold readiness until the schema is fully migrated, so the UI
+                // never loads against a partially-migrated database
+                Ok(_)
+                    if health_config.ready_after_migrations
+                        && migration_tracker.phase() == MigrationPhase::Running =>
+                {
+                    debug!("Backend is healthy, waiting for migrations to finish...");
                 }
-                Err(e) => {
-                    debug!("Health check failed: {} (waiting...)", e);
+                Ok(_) => {
+                    let elapsed = start_time.elapsed();
+                    info!(
+                        "Backend is ready! Startup took {:.2}s",
+                        elapsed.as_secs_f64()
+                    );
+                    health_timer.finish(app);
+                    break;
+                }
+                Err(reason) => {
+                    debug!("Health check not passed yet: {} (waiting...)", reason);
                 }
             }
 
             // Check if we've exceeded max wait time
-            if start_time.elapsed() > max_wait {
-                let error_msg = "Backend server did not become ready within 30 seconds";
+            let deadline =
+                migration_tracker.health_deadline(start_time, startup_params.startup_timeout);
+            if std::time::Instant::now() > deadline {
+                let error_msg = format!(
+                    "Backend server did not become ready within {:.0} seconds (migrations: {:?})",
+                    start_time.elapsed().as_secs_f64(),
+                    migration_tracker.phase()
+                );
                 error!("{}", error_msg);
                 return Err(error_msg.into());
             }
 
-            std::thread::sleep(poll_interval);
+            // Never sleep past the deadline
+            let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+            std::thread::sleep(poll_interval.min(remaining));
+            poll_interval = (poll_interval * 2).min(HEALTH_POLL_MAX);
         }
 
         // Final verification that process is still running
         match child.try_wait() {
             Ok(Some(status)) => {
-                let error_msg = format!(
-                    "Backend server exited shortly after becoming ready with status: {:?}",
-                    status
-                );
-                error!("{}", error_msg);
-                return Err(error_msg.into());
+                return Err(report_backend_exit(
+                    app,
+                    "exited shortly after becoming ready",
+                    status,
+                    &stderr,
+                )
+                .into());
             }
             Ok(None) => {
                 info!("Backend server process is running and healthy");
@@ -819,6 +1279,7 @@ fn start_backend_server(
             }
         }
 
+        events::emit(app, "backend://ready", port);
         return Ok(child);
     }
 
@@ -829,52 +1290,17 @@ fn start_backend_server(
     );
 
     // Try to find Python in virtual environment first, then system Python
-    let python_cmd = {
-        // Check Windows path first (Scripts/python.exe)
-        let venv_python_windows = backend_path
-            .join(".venv")
-            .join("Scripts")
-            .join("python.exe");
-        // Check Unix path (bin/python)
-        let venv_python_unix = backend_path.join(".venv").join("bin").join("python");
-
-        if venv_python_windows.exists() {
-            info!(
-                "Using virtual environment Python (Windows): {:?}",
-                venv_python_windows
-            );
-            venv_python_windows
-        } else if venv_python_unix.exists() {
-            info!(
-                "Using virtual environment Python (Unix): {:?}",
-                venv_python_unix
-            );
-            venv_python_unix
-        } else {
-            // Try python3, then python - use a timeout to avoid hanging
-            let check_python = |cmd: &str| -> bool {
-                Command::new(cmd)
-                    .arg("--version")
-                    .stdout(std::process::Stdio::null())
-                    .stderr(std::process::Stdio::null())
-                    .output()
-                    .is_ok()
-            };
-
-            if check_python("python3") {
-                PathBuf::from("python3")
-            } else if check_python("python") {
-                PathBuf::from("python")
-            } else {
-                return Err("Python not found. Please install Python 3.10+ from https://www.python.org/downloads/ and run setup-backend.ps1, or build the app with build.ps1 to create a bundled backend executable".into());
-            }
-        }
+    let Some(python_cmd) = find_python(backend_path) else {
+        return Err("Python not found. Please install Python 3.10+ from https://www.python.org/downloads/ and run setup-backend.ps1, or build the app with build.ps1 to create a bundled backend executable".into());
     };
 
     // Check if dependencies are installed
-    if !check_backend_dependencies(&python_cmd) {
+    let deps_timer = PhaseTimer::start("dependency_check");
+    let deps_ready = check_backend_dependencies(&python_cmd);
+    deps_timer.finish(app);
+    if !deps_ready {
         warn!("Backend dependencies not found. Attempting to set up automatically...");
-        if !setup_backend_dependencies(backend_path, &python_cmd) {
+        if !setup_backend_dependencies(app, backend_path, &python_cmd) {
             return Err(format!(
         "Backend dependencies are not installed. Please run setup-backend.ps1 from the project root directory, or build the app with build.ps1 to create a bundled backend executable.\n\
         Backend path: {:?}\n\
@@ -884,13 +1310,44 @@ fn start_backend_server(
         }
     }
 
+    let python_version = diagnostics::detect_python_version(&python_cmd);
+    info!("Python version: {:?}", python_version);
+    record_startup_info(app, |startup| {
+        startup.backend_mode = Some(BackendMode::Python {
+            interpreter: python_cmd.clone(),
+            backend_dir: backend_path.to_path_buf(),
+        });
+        startup.python_version = python_version;
+        startup.database_path = Some(db_path.to_path_buf());
+    });
+
     // Run migrations in background - don't block server startup
     // Migrations will run concurrently with server startup
     let backend_path_clone = backend_path.to_path_buf();
     let db_path_clone = db_path.to_path_buf();
     let python_cmd_clone = python_cmd.clone();
+    let app_for_migrations = app.clone();
+    // Lets the fail-fast health wait below follow migration progress, as in the bundled path
+    let migration_tracker = MigrationTracker::default();
+    let migration_tracker_thread = migration_tracker.clone();
+    let confirm_migrations = config::current(app).migrate_confirm;
+    let read_only = config::current(app).read_only;
+    if !confirm_migrations && !read_only {
+        migration_tracker.set(MigrationPhase::Running);
+    }
     std::thread::spawn(move || {
+        if read_only {
+            info!("Read-only mode, skipping database migrations");
+            return;
+        }
+        if confirm_migrations {
+            // Leave schema changes to the user: report pending migrations instead of applying them
+            migrations::notify_if_pending(&app_for_migrations);
+            return;
+        }
         info!("Running database migrations in background...");
+        let migrate_timer =
+            PhaseTimer::start_measured(&app_for_migrations, "migrations", CpuScope::Children);
         let mut migrate_cmd = Command::new(&python_cmd_clone);
         migrate_cmd.current_dir(&backend_path_clone);
         migrate_cmd.arg("manage.py");
@@ -900,11 +1357,8 @@ fn start_backend_server(
         migrate_cmd.env("DJANGO_SETTINGS_MODULE", "config.settings");
 
         // Hide console window on Windows (but keep output capture for .output())
-        #[cfg(windows)]
-        {
-            const CREATE_NO_WINDOW: u32 = 0x08000000;
-            migrate_cmd.creation_flags(CREATE_NO_WINDOW);
-        }
+        process::configure_command(&mut migrate_cmd);
+        process::use_c_locale(&mut migrate_cmd);
 
         match migrate_cmd.output() {
             Ok(output) => {
@@ -914,6 +1368,7 @@ fn start_backend_server(
                     if !stdout.is_empty() {
                         info!("Migration output: {}", stdout);
                     }
+                    migrations::notify_done(&app_for_migrations, &stdout);
                 } else {
                     let stderr = String::from_utf8_lossy(&output.stderr);
                     let stdout = String::from_utf8_lossy(&output.stdout);
@@ -928,6 +1383,8 @@ fn start_backend_server(
                 warn!("Could not run migrations: {}. Server is running anyway.", e);
             }
         }
+        migrate_timer.finish(&app_for_migrations);
+        migration_tracker_thread.set(MigrationPhase::Finished(std::time::Instant::now()));
     });
 
     // Start the server immediately without waiting for migrations
@@ -935,267 +1392,220 @@ fn start_backend_server(
     cmd.current_dir(backend_path);
     cmd.arg("manage.py");
     cmd.arg("runserver");
-    cmd.arg("127.0.0.1:8000");
+    cmd.arg(SocketAddr::new(backend_host(app), port).to_string());
     cmd.env("DATABASE_PATH", db_path.to_string_lossy().to_string());
     cmd.env("DJANGO_SETTINGS_MODULE", "config.settings");
+    apply_backend_env(app, &mut cmd);
 
-    // Hide console window on Windows and suppress output
-    #[cfg(windows)]
-    {
-        // CREATE_NO_WINDOW flag prevents console window from appearing
-        const CREATE_NO_WINDOW: u32 = 0x08000000;
-        cmd.creation_flags(CREATE_NO_WINDOW);
+    // No console window on Windows; a separate process group lets the backend receive CTRL_BREAK
+    // on shutdown, and its own session on Unix lets the autoreloader's children be killed as a group
+    process::configure_backend_command(&mut cmd);
+
+    // Output is only captured for the developer console; otherwise the backend stays silent
+    if config::current(app).dev_console {
+        cmd.stdout(Stdio::piped());
+        cmd.stderr(Stdio::piped());
+    } else {
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
     }
 
-    // Suppress stdout and stderr to keep backend completely hidden
-    cmd.stdout(Stdio::null());
-    cmd.stderr(Stdio::null());
+    // A misconfigured backend fails here with Django's own messages instead of exiting later
+    if config::current(app).preflight_check {
+        management::preflight(app)?;
+    }
+
+    // The port can take a moment to be released after cleanup, so don't race the spawn against it
+    if !port::wait_until_free(port, port::PORT_RELEASE_TIMEOUT) {
+        let error_msg = format!("Port {} is still in use, cannot start the backend", port);
+        error!("{}", error_msg);
+        return Err(error_msg.into());
+    }
 
-    let mut child = cmd.spawn()?;
+    let spawn_timer = PhaseTimer::start("spawn");
+    let mut child = spawn_with_retry(app, &mut cmd)?;
+    spawn_timer.finish(app);
     info!("Backend server started with PID: {:?}", child.id());
+    diagnostics::record_spawn(app, child.id());
+    let stderr = output::forward(app, &mut child);
 
-    // Quick non-blocking check if process started successfully
-    match child.try_wait() {
-        Ok(Some(status)) => {
+    // Check that the process didn't exit right away
+    check_spawned(app, &mut child, &stderr)?;
+
+    // CI needs a backend that is actually up, or the error right away. The deadline adapts to
+    // migrations like the bundled path's health poll
+    if fail_fast() {
+        let start_time = std::time::Instant::now();
+        let startup_timeout = health::startup_params(app).startup_timeout;
+        if !wait_for_health_until(app, || {
+            migration_tracker.health_deadline(start_time, startup_timeout)
+        }) {
+            kill_backend_process(&mut child);
             return Err(format!(
-                "Backend server exited immediately with status: {:?}",
-                status
+                "Backend server did not become ready ({} is set)",
+                FAIL_FAST_ENV
             )
             .into());
         }
-        Ok(None) => {
-            info!("Backend server process is running");
-        }
-        Err(e) => {
-            return Err(format!("Error checking backend server status: {}", e).into());
-        }
+        return Ok(child);
     }
 
     // Don't wait for server readiness - return immediately
-    // The frontend will handle connection retries if needed
+    // The frontend will handle connection retries if needed, so there is no deadline for slow
+    // migrations to outlast; their result still arrives as `backend://migrations_done`
     info!("Backend server process started, returning immediately (server may not be ready yet)");
 
     Ok(child)
 }
 
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-    // Fix grey window issue on Linux by setting WebKit environment variables
-    // This disables problematic rendering features that cause EGL errors
-    #[cfg(target_os = "linux")]
-    {
-        use std::env;
-        // Disable DMABUF renderer to fix EGL_BAD_PARAMETER errors
-        // Safety: Setting environment variables is safe in single-threaded context before Tauri starts
-        if env::var("WEBKIT_DISABLE_DMABUF_RENDERER").is_err() {
-            unsafe {
-                env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
+/// Candidate executable paths inside the resource directory, platform-specific name first
+/// The listing is diagnostic only: the candidates are probed directly, so a directory that
+/// denies listing can still yield the executable
+fn resource_dir_exe_paths(resource_dir: &Path) -> Vec<PathBuf> {
+    match std::fs::read_dir(resource_dir) {
+        Ok(entries) => {
+            info!("Resource directory exists, listing contents:");
+            for entry in entries.flatten() {
+                info!("  - {:?}", entry.path());
             }
         }
-        // Disable compositing mode to avoid rendering issues
-        if env::var("WEBKIT_DISABLE_COMPOSITING_MODE").is_err() {
-            unsafe {
-                env::set_var("WEBKIT_DISABLE_COMPOSITING_MODE", "1");
-            }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            warn!("Resource directory does not exist: {:?}", resource_dir);
         }
-        // Force X11 backend if on Wayland (more stable for WebKit)
-        if env::var("GDK_BACKEND").is_err() && env::var("WAYLAND_DISPLAY").is_ok() {
-            unsafe {
-                env::set_var("GDK_BACKEND", "x11");
-            }
+        Err(e) => {
+            warn!(
+                "Could not list resource directory {:?}: {} (probing candidate paths anyway)",
+                resource_dir, e
+            );
         }
     }
+    // Resources may be in a 'resources' subdirectory (AppImage structure)
+    let (first, second) = if cfg!(windows) {
+        ("backend-server.exe", "backend-server")
+    } else {
+        ("backend-server", "backend-server.exe")
+    };
+    vec![
+        resource_dir.join("resources").join(first),
+        resource_dir.join("resources").join(second),
+        resource_dir.join(first),
+        resource_dir.join(second),
+    ]
+}
 
-    // Store backend process handle in app state
-    let backend_process: Mutex<Option<Child>> = Mutex::new(None);
+/// Every place a bundled backend executable may be, in priority order
+/// Used when no `backend.manifest.json` declares the executable
+fn heuristic_exe_paths(app_handle: &tauri::AppHandle, exe_dir: &Path) -> Vec<PathBuf> {
+    let mut possible_exe_paths: Vec<PathBuf> = trusted_backend_exe()
+        .map(Path::to_path_buf)
+        .into_iter()
+        .collect();
+
+    // First, check backend/dist directory (development build location), from the executable
+    // directory and from the current working directory (for development)
+    let base_dirs: Vec<PathBuf> = std::iter::once(exe_dir.to_path_buf())
+        .chain(std::env::current_dir().ok())
+        .collect();
+    possible_exe_paths.extend(backend_dist_exe_paths(&base_dirs));
 
-    tauri::Builder::default()
-    .manage(backend_process)
-    .setup(move |app| {
-      // Enable logging in both debug and release modes for troubleshooting
-      // Don't fail if logging plugin fails to initialize
-      let _ = app.handle().plugin(
-        tauri_plugin_log::Builder::default()
-          .level(log::LevelFilter::Info)
-          .build(),
-      );
-
-      // Get app data directory for database - don't fail if this doesn't work
-      let db_path = match app.path().app_data_dir() {
-        Ok(dir) => {
-          let _ = std::fs::create_dir_all(&dir);
-          dir.join("db.sqlite3")
+    // Try Tauri resource resolution (for bundled resources)
+    match app_handle.path().resource_dir() {
+        Ok(resource_dir) => {
+            info!("Resource directory resolved: {:?}", resource_dir);
+            possible_exe_paths.extend(resource_dir_exe_paths(&resource_dir));
         }
         Err(e) => {
-          eprintln!("Failed to get app data directory: {}, using fallback", e);
-          // Fallback to current directory
-          std::env::current_dir()
-            .unwrap_or_else(|_| PathBuf::from("."))
-            .join("db.sqlite3")
-        }
-      };
-
-      // Move all blocking operations to a background thread to prevent UI hang
-      let app_handle = app.handle().clone();
-      let db_path_clone = db_path.clone();
-      std::thread::spawn(move || {
-        // Initialize database on startup - don't fail if this doesn't work
-        if let Err(e) = initialize_database(&app_handle) {
-          eprintln!("Database initialization warning: {}", e);
-          // Don't fail startup if database init fails - it will be created on first use
-        }
-
-        // Get app data directory for storing backend executable
-        let app_data_dir = match app_handle.path().app_data_dir() {
-          Ok(dir) => {
-            let _ = std::fs::create_dir_all(&dir);
-            dir
-          }
-          Err(e) => {
-            error!("Failed to get app data directory: {}", e);
-            std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."))
-          }
-        };
-
-        info!("App data directory: {:?}", app_data_dir);
-
-        // Path where backend-server should be stored in app data directory
-        #[cfg(windows)]
-        let backend_exe_name = "backend-server.exe";
-        #[cfg(not(windows))]
-        let backend_exe_name = "backend-server";
-
-        let installed_backend_path = app_data_dir.join(backend_exe_name);
-
-        // First, try to find bundled backend executable (for release builds)
-        let exe_path = std::env::current_exe().unwrap_or_default();
-        let exe_dir = exe_path.parent().unwrap_or(std::path::Path::new("."));
-
-        info!("Looking for bundled backend executable...");
-        info!("Executable path: {:?}", exe_path);
-        info!("Executable directory: {:?}", exe_dir);
-
-        let mut possible_exe_paths: Vec<PathBuf> = vec![];
-
-        // First, check backend/dist directory (development build location)
-        // Try to find the project root by going up from executable directory
-        let mut check_backend_dist = |base_dir: &std::path::Path| {
-          // Try various relative paths to find backend/dist
-          let candidates = vec![
-            base_dir.join("backend").join("dist"),
-            base_dir.join("..").join("backend").join("dist"),
-            base_dir.join("../..").join("backend").join("dist"),
-            base_dir.join("../../..").join("backend").join("dist"),
-            base_dir.join("../../../..").join("backend").join("dist"),
-          ];
-
-          for backend_dist in candidates {
-            let backend_dist = backend_dist.canonicalize().unwrap_or(backend_dist);
-            #[cfg(windows)]
-            {
-              possible_exe_paths.push(backend_dist.join("backend-server.exe"));
-              possible_exe_paths.push(backend_dist.join("backend-server"));
-            }
-            #[cfg(not(windows))]
-            {
-              possible_exe_paths.push(backend_dist.join("backend-server"));
-              possible_exe_paths.push(backend_dist.join("backend-server.exe"));
-            }
-          }
-        };
-
-        // Check from executable directory
-        check_backend_dist(exe_dir);
-
-        // Also check from current working directory (for development)
-        if let Ok(current_dir) = std::env::current_dir() {
-          check_backend_dist(&current_dir);
-        }
-
-        // Try Tauri resource resolution (for bundled resources)
-        match app_handle.path().resource_dir() {
-          Ok(resource_dir) => {
-            info!("Resource directory resolved: {:?}", resource_dir);
-            // Check if resource directory exists
-            if resource_dir.exists() {
-              info!("Resource directory exists, listing contents:");
-              if let Ok(entries) = std::fs::read_dir(&resource_dir) {
-                for entry in entries.flatten() {
-                  info!("  - {:?}", entry.path());
-                }
-              }
-            } else {
-              warn!("Resource directory does not exist: {:?}", resource_dir);
-            }
-            // Prioritize platform-specific executables
-            // Note: Resources may be in a 'resources' subdirectory (AppImage structure)
-            #[cfg(windows)]
-            {
-              possible_exe_paths.push(resource_dir.join("resources").join("backend-server.exe"));
-              possible_exe_paths.push(resource_dir.join("resources").join("backend-server"));
-              possible_exe_paths.push(resource_dir.join("backend-server.exe"));
-              possible_exe_paths.push(resource_dir.join("backend-server"));
-            }
-            #[cfg(not(windows))]
-            {
-              possible_exe_paths.push(resource_dir.join("resources").join("backend-server"));
-              possible_exe_paths.push(resource_dir.join("resources").join("backend-server.exe"));
-              possible_exe_paths.push(resource_dir.join("backend-server"));
-              possible_exe_paths.push(resource_dir.join("backend-server.exe"));
-            }
-          }
-          Err(e) => {
             warn!("Could not resolve resource directory: {}", e);
-          }
         }
+    }
 
-        // Also try resolving the resource directly using Tauri's resolve method
-        // This might work better in some bundle configurations
-        // Note: In Tauri v2, resolve might work differently, so we try both approaches
-        // Prioritize platform-specific executables
-        #[cfg(not(windows))]
+    // Also try resolving the resource directly using Tauri's resolve method
+    // This might work better in some bundle configurations
+    // Note: In Tauri v2, resolve might work differently, so we try both approaches
+    // Prioritize platform-specific executables
+    #[cfg(not(windows))]
+    {
+        if let Ok(resource_path) = app_handle
+            .path()
+            .resolve("backend-server", tauri::path::BaseDirectory::Resource)
         {
-          if let Ok(resource_path) = app_handle.path().resolve("backend-server", tauri::path::BaseDirectory::Resource) {
-            info!("Resolved resource path (backend-server): {:?}", resource_path);
+            info!(
+                "Resolved resource path (backend-server): {:?}",
+                resource_path
+            );
             possible_exe_paths.push(resource_path);
-          }
         }
-        #[cfg(windows)]
+    }
+    #[cfg(windows)]
+    {
+        if let Ok(resource_path) = app_handle
+            .path()
+            .resolve("backend-server.exe", tauri::path::BaseDirectory::Resource)
         {
-          if let Ok(resource_path) = app_handle.path().resolve("backend-server.exe", tauri::path::BaseDirectory::Resource) {
-            info!("Resolved resource path (backend-server.exe): {:?}", resource_path);
+            info!(
+                "Resolved resource path (backend-server.exe): {:?}",
+                resource_path
+            );
             possible_exe_paths.push(resource_path);
-          }
-          if let Ok(resource_path) = app_handle.path().resolve("backend-server", tauri::path::BaseDirectory::Resource) {
-            info!("Resolved resource path (backend-server): {:?}", resource_path);
+        }
+        if let Ok(resource_path) = app_handle
+            .path()
+            .resolve("backend-server", tauri::path::BaseDirectory::Resource)
+        {
+            info!(
+                "Resolved resource path (backend-server): {:?}",
+                resource_path
+            );
             possible_exe_paths.push(resource_path);
-          }
         }
+    }
 
-        // For Linux AppImages, resources might be in a different location
-        // AppImages extract to a temporary directory, and resources are in usr/lib or usr/share
-        #[cfg(target_os = "linux")]
-        {
-          // Check AppImage extraction directory structure
-          if let Ok(appimage_path) = std::env::var("APPIMAGE") {
+    // For Linux AppImages, resources might be in a different location
+    // AppImages extract to a temporary directory, and resources are in usr/lib or usr/share
+    #[cfg(target_os = "linux")]
+    {
+        // Check AppImage extraction directory structure
+        if let Ok(appimage_path) = std::env::var("APPIMAGE") {
             info!("Running as AppImage: {}", appimage_path);
             if let Ok(appdir) = std::env::var("APPDIR") {
-              info!("AppImage APPDIR: {}", appdir);
-              let appdir_path = PathBuf::from(&appdir);
-              // AppImage structure: usr/lib/ProductName/resources/backend-server
-              possible_exe_paths.push(appdir_path.join("usr").join("lib").join("Budget Planer").join("resources").join("backend-server"));
-              possible_exe_paths.push(appdir_path.join("usr").join("lib").join("budget-planer").join("resources").join("backend-server"));
-              possible_exe_paths.push(appdir_path.join("usr").join("lib").join("com.budgetplaner").join("resources").join("backend-server"));
-              // Also check without resources subdirectory
-              possible_exe_paths.push(appdir_path.join("usr").join("lib").join("backend-server"));
-              possible_exe_paths.push(appdir_path.join("usr").join("share").join("backend-server"));
-              possible_exe_paths.push(appdir_path.join("resources").join("backend-server"));
+                info!("AppImage APPDIR: {}", appdir);
+                let appdir_path = PathBuf::from(&appdir);
+                // AppImage structure: usr/lib/ProductName/resources/backend-server
+                possible_exe_paths.push(
+                    appdir_path
+                        .join("usr")
+                        .join("lib")
+                        .join("Budget Planer")
+                        .join("resources")
+                        .join("backend-server"),
+                );
+                possible_exe_paths.push(
+                    appdir_path
+                        .join("usr")
+                        .join("lib")
+                        .join("budget-planer")
+                        .join("resources")
+                        .join("backend-server"),
+                );
+                possible_exe_paths.push(
+                    appdir_path
+                        .join("usr")
+                        .join("lib")
+                        .join("com.budgetplaner")
+                        .join("resources")
+                        .join("backend-server"),
+                );
+                // Also check without resources subdirectory
+                possible_exe_paths.push(appdir_path.join("usr").join("lib").join("backend-server"));
+                possible_exe_paths
+                    .push(appdir_path.join("usr").join("share").join("backend-server"));
+                possible_exe_paths.push(appdir_path.join("resources").join("backend-server"));
             }
-          }
+        }
 
-          // For DEB packages, resources are typically in /usr/lib or /usr/share
-          // Check if we're in a system installation
-          if exe_dir.starts_with("/usr") {
+        // For DEB packages, resources are typically in /usr/lib or /usr/share
+        // Check if we're in a system installation
+        if exe_dir.starts_with("/usr") {
             possible_exe_paths.push(PathBuf::from("/usr/lib/budget-planer/backend-server"));
             possible_exe_paths.push(PathBuf::from("/usr/share/budget-planer/backend-server"));
             possible_exe_paths.push(PathBuf::from("/usr/lib/com.budgetplaner/backend-server"));
@@ -1203,460 +1613,1359 @@ pub fn run() {
             // Also check with space in name (from productName)
             possible_exe_paths.push(PathBuf::from("/usr/lib/Budget Planer/backend-server"));
             possible_exe_paths.push(PathBuf::from("/usr/share/Budget Planer/backend-server"));
-          }
+        }
 
-          // For standalone binaries, check common project locations
-          // This is useful when running the binary from the project directory or Downloads
-          let home_dir = std::env::var("HOME").ok().map(PathBuf::from);
-          if let Some(home) = home_dir {
+        // For standalone binaries, check common project locations
+        // This is useful when running the binary from the project directory or Downloads
+        let home_dir = std::env::var("HOME").ok().map(PathBuf::from);
+        if let Some(home) = home_dir {
             // Check common project locations in home directory
             let project_locations = vec![
-              home.join("Dokumente").join("GitHub").join("Budget-Planer").join("backend").join("dist"),
-              home.join("Documents").join("GitHub").join("Budget-Planer").join("backend").join("dist"),
-              home.join("projects").join("Budget-Planer").join("backend").join("dist"),
-              home.join("Projects").join("Budget-Planer").join("backend").join("dist"),
-              home.join("dev").join("Budget-Planer").join("backend").join("dist"),
-              home.join("Dev").join("Budget-Planer").join("backend").join("dist"),
+                home.join("Dokumente")
+                    .join("GitHub")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
+                home.join("Documents")
+                    .join("GitHub")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
+                home.join("projects")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
+                home.join("Projects")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
+                home.join("dev")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
+                home.join("Dev")
+                    .join("Budget-Planer")
+                    .join("backend")
+                    .join("dist"),
             ];
 
             for project_path in project_locations {
-              if project_path.exists() {
-                info!("Found potential project directory: {:?}", project_path);
-                possible_exe_paths.push(project_path.join("backend-server"));
-              }
+                if project_path.exists() {
+                    info!("Found potential project directory: {:?}", project_path);
+                    possible_exe_paths.push(project_path.join("backend-server"));
+                }
             }
-          }
+        }
 
-          // Also check if BACKEND_SERVER_PATH environment variable is set
-          if let Ok(backend_path) = std::env::var("BACKEND_SERVER_PATH") {
+        // Also check if BACKEND_SERVER_PATH environment variable is set
+        if let Ok(backend_path) = std::env::var("BACKEND_SERVER_PATH") {
             let backend_path_buf = PathBuf::from(&backend_path);
             if backend_path_buf.exists() {
-              info!("Using backend server from BACKEND_SERVER_PATH: {:?}", backend_path_buf);
-              possible_exe_paths.push(backend_path_buf);
+                info!(
+                    "Using backend server from BACKEND_SERVER_PATH: {:?}",
+                    backend_path_buf
+                );
+                possible_exe_paths.push(backend_path_buf);
             }
-          }
         }
+    }
 
-        // Add paths relative to executable (fallback)
-        // For standalone binaries, resources might be next to the executable
-        // Prioritize platform-specific executables
-        #[cfg(windows)]
-        {
-          possible_exe_paths.push(exe_dir.join("backend-server.exe"));
-          possible_exe_paths.push(exe_dir.join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("resources").join("backend-server.exe"));
-          possible_exe_paths.push(exe_dir.join("resources").join("backend-server"));
-        }
-        #[cfg(not(windows))]
-        {
-          possible_exe_paths.push(exe_dir.join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("backend-server.exe"));
-          possible_exe_paths.push(exe_dir.join("resources").join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("resources").join("backend-server.exe"));
-        }
+    // Add paths relative to executable (fallback)
+    // For standalone binaries, resources might be next to the executable
+    // Prioritize platform-specific executables
+    #[cfg(windows)]
+    {
+        possible_exe_paths.push(exe_dir.join("backend-server.exe"));
+        possible_exe_paths.push(exe_dir.join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("resources").join("backend-server.exe"));
+        possible_exe_paths.push(exe_dir.join("resources").join("backend-server"));
+    }
+    #[cfg(not(windows))]
+    {
+        possible_exe_paths.push(exe_dir.join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("backend-server.exe"));
+        possible_exe_paths.push(exe_dir.join("resources").join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("resources").join("backend-server.exe"));
+    }
 
-        // For Linux, also check lib and share directories relative to executable
-        // This is common for Linux applications and standalone binaries
-        #[cfg(target_os = "linux")]
-        {
-          possible_exe_paths.push(exe_dir.join("lib").join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("share").join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("usr").join("lib").join("backend-server"));
-          possible_exe_paths.push(exe_dir.join("usr").join("share").join("backend-server"));
-        }
+    // For Linux, also check lib and share directories relative to executable
+    // This is common for Linux applications and standalone binaries
+    #[cfg(target_os = "linux")]
+    {
+        possible_exe_paths.push(exe_dir.join("lib").join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("share").join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("usr").join("lib").join("backend-server"));
+        possible_exe_paths.push(exe_dir.join("usr").join("share").join("backend-server"));
+    }
 
-        // Also check parent directories (for nested bundle structures)
-        if let Some(parent) = exe_dir.parent() {
-          #[cfg(windows)]
-          {
+    // Also check parent directories (for nested bundle structures)
+    if let Some(parent) = exe_dir.parent() {
+        #[cfg(windows)]
+        {
             possible_exe_paths.push(parent.join("backend-server.exe"));
             possible_exe_paths.push(parent.join("backend-server"));
             possible_exe_paths.push(parent.join("resources").join("backend-server.exe"));
             possible_exe_paths.push(parent.join("resources").join("backend-server"));
-          }
-          #[cfg(not(windows))]
-          {
+        }
+        #[cfg(not(windows))]
+        {
             possible_exe_paths.push(parent.join("backend-server"));
             possible_exe_paths.push(parent.join("backend-server.exe"));
             possible_exe_paths.push(parent.join("resources").join("backend-server"));
             possible_exe_paths.push(parent.join("resources").join("backend-server.exe"));
-          }
+        }
 
-          #[cfg(target_os = "linux")]
-          {
+        #[cfg(target_os = "linux")]
+        {
             possible_exe_paths.push(parent.join("lib").join("backend-server"));
             possible_exe_paths.push(parent.join("share").join("backend-server"));
-          }
         }
+    }
 
-        // Log all paths being checked
-        info!("Checking the following paths for backend executable:");
-        for path in &possible_exe_paths {
-          let exists = path.exists();
-          info!("  {:?} - {}", path, if exists { "EXISTS" } else { "not found" });
+    // Administrators can lock discovery down to known directories
+    let discovery_roots = config::current(app_handle).discovery_roots;
+    if !discovery_roots.is_empty() {
+        info!("Restricting discovery to {:?}", discovery_roots);
+        for root in &discovery_roots {
+            #[cfg(windows)]
+            possible_exe_paths.push(root.join("backend-server.exe"));
+            possible_exe_paths.push(root.join("backend-server"));
         }
+        possible_exe_paths.retain(|path| discovery_allowed(app_handle, path));
+    }
 
-        // Check if backend is already installed in app data directory
-        let backend_installed = installed_backend_path.exists() && {
-          if let Ok(metadata) = std::fs::metadata(&installed_backend_path) {
-            metadata.len() >= 1024 // Not a placeholder
-          } else {
-            false
-          }
-        };
+    dedup_by_canonical_path(possible_exe_paths)
+}
 
-        if backend_installed {
-          info!("Backend already installed at: {:?}", installed_backend_path);
+/// Candidates when `backend.manifest.json` declares the executable: the trusted QA override and
+/// the declared path, subject to `discovery_roots` like the heuristic candidates
+fn manifest_exe_paths(
+    app_handle: &tauri::AppHandle,
+    manifest: &manifest::BackendManifest,
+) -> Vec<PathBuf> {
+    let mut possible_exe_paths: Vec<PathBuf> = trusted_backend_exe()
+        .map(Path::to_path_buf)
+        .into_iter()
+        .chain([manifest.executable.clone()])
+        .collect();
+    if !config::current(app_handle).discovery_roots.is_empty() {
+        possible_exe_paths.retain(|path| discovery_allowed(app_handle, path));
+        if !possible_exe_paths.contains(&manifest.executable) {
+            warn!(
+                "Executable declared by {:?} is outside discovery_roots, ignoring it",
+                manifest.source
+            );
         }
+    }
+    dedup_by_canonical_path(possible_exe_paths)
+}
 
-        // Find the first existing executable from bundled/source locations
-        let bundled_exe = possible_exe_paths.iter().find(|p| {
-          if !p.exists() {
-            return false;
-          }
+/// Executable paths in the `backend/dist` directories found by going up from each base directory
+/// Directories are compared canonically, so symlinks and `..` aliases of the same `backend/dist`
+/// aren't probed twice
+fn backend_dist_exe_paths(base_dirs: &[PathBuf]) -> Vec<PathBuf> {
+    let mut seen_backend_dists = std::collections::HashSet::new();
+    let mut paths = Vec::new();
+    for base_dir in base_dirs {
+        // Try various relative paths to find backend/dist
+        let candidates = vec![
+            base_dir.join("backend").join("dist"),
+            base_dir.join("..").join("backend").join("dist"),
+            base_dir.join("../..").join("backend").join("dist"),
+            base_dir.join("../../..").join("backend").join("dist"),
+            base_dir.join("../../../..").join("backend").join("dist"),
+        ];
 
-          // On non-Windows, skip .exe files (they're Windows executables)
-          #[cfg(not(windows))]
-          {
-            if p.file_name().and_then(|n| n.to_str()).map(|s| s.ends_with(".exe")).unwrap_or(false) {
-              return false;
+        for backend_dist in candidates {
+            // Only existing directories canonicalize; missing ones can't hold the executable
+            let Ok(backend_dist) = backend_dist.canonicalize() else {
+                continue;
+            };
+            if !seen_backend_dists.insert(backend_dist.clone()) {
+                continue;
             }
-          }
-
-          // Filter out placeholder files (very small files < 1KB are likely placeholders)
-          if let Ok(metadata) = std::fs::metadata(p) {
-            let size = metadata.len();
-            if size < 1024 {
-              warn!("Skipping potential placeholder file: {:?} (size: {} bytes)", p, size);
-              return false;
+            #[cfg(windows)]
+            {
+                paths.push(backend_dist.join("backend-server.exe"));
+                paths.push(backend_dist.join("backend-server"));
             }
-          }
-
-          true
-        }).cloned();
-
-        // Determine which backend executable to use
-        // Priority: 1. Installed in app data dir (if up to date), 2. Bundled/source
-        let backend_to_use: Option<PathBuf> = if backend_installed {
-          // Check if bundled version is newer (compare file sizes as simple heuristic,
-          // or always prefer installed version for consistency)
-          if let Some(ref bundled) = bundled_exe {
+            #[cfg(not(windows))]
+            {
+                paths.push(backend_dist.join("backend-server"));
+                paths.push(backend_dist.join("backend-server.exe"));
+            }
+        }
+    }
+    paths
+}
+
+/// Drop candidates naming a file already listed, e.g. when the resource directory is the
+/// executable directory; existing paths are compared canonically, missing ones as written
+fn dedup_by_canonical_path(paths: Vec<PathBuf>) -> Vec<PathBuf> {
+    let mut seen = std::collections::HashSet::new();
+    paths
+        .into_iter()
+        .filter(|path| seen.insert(path.canonicalize().unwrap_or_else(|_| path.clone())))
+        .collect()
+}
+
+/// Whether a discovered candidate can be launched: it exists, suits the platform, and isn't a
+/// placeholder (unless it was declared by the manifest, which verified it already)
+fn usable_backend_exe(candidate: &diagnostics::DiscoveryCandidate, declared: bool) -> bool {
+    let p = &candidate.path;
+    let Some(size) = candidate.size else {
+        return false;
+    };
+
+    // On non-Windows, skip .exe files (they're Windows executables)
+    #[cfg(not(windows))]
+    {
+        if p.file_name()
+            .and_then(|n| n.to_str())
+            .map(|s| s.ends_with(".exe"))
+            .unwrap_or(false)
+        {
+            return false;
+        }
+    }
+
+    // Filter out placeholder files (very small files < 1KB are likely placeholders)
+    if trusted_backend_exe() == Some(p.as_path()) || declared {
+        return true;
+    }
+    if size < 1024 {
+        warn!(
+            "Skipping potential placeholder file: {:?} (size: {} bytes)",
+            p, size
+        );
+        return false;
+    }
+
+    true
+}
+
+/// Probe the installed copy in the app data directory (always first) and every place the
+/// manifest or the built-in search names for the backend executable
+fn scan_backend_candidates(
+    app: &tauri::AppHandle,
+) -> Result<
+    (
+        Option<manifest::BackendManifest>,
+        Vec<diagnostics::DiscoveryCandidate>,
+    ),
+    String,
+> {
+    let installed_backend_path = storage::app_data_dir(app)?.join(BACKEND_EXE_NAME);
+    let exe_path = std::env::current_exe().unwrap_or_default();
+    let exe_dir = exe_path.parent().unwrap_or(std::path::Path::new("."));
+
+    let manifest = manifest::load(app)?;
+    let possible_exe_paths = match &manifest {
+        Some(manifest) => manifest_exe_paths(app, manifest),
+        None => heuristic_exe_paths(app, exe_dir),
+    };
+    let all_paths: Vec<PathBuf> = std::iter::once(installed_backend_path)
+        .chain(possible_exe_paths)
+        .collect();
+    Ok((manifest, diagnostics::probe_candidates(&all_paths)))
+}
+
+/// Whether a usable backend executable exists, only a placeholder (a stub under 1KB from a
+/// build that skipped the backend), or nothing at all
+fn backend_binary_status(app: &tauri::AppHandle) -> Result<diagnostics::BinaryStatus, String> {
+    let (manifest, candidates) = scan_backend_candidates(app)?;
+    let Some((installed, bundled)) = candidates.split_first() else {
+        return Ok(diagnostics::BinaryStatus::Missing);
+    };
+
+    // The installed copy is only trusted when it matches what the manifest pins
+    if installed.size.is_some_and(|size| size >= 1024)
+        && manifest
+            .as_ref()
+            .is_none_or(|manifest| manifest.matches(&installed.path))
+    {
+        return Ok(diagnostics::BinaryStatus::Real {
+            path: installed.path.clone(),
+        });
+    }
+    if let Some(candidate) = bundled
+        .iter()
+        .find(|candidate| usable_backend_exe(candidate, manifest.is_some()))
+    {
+        return Ok(diagnostics::BinaryStatus::Real {
+            path: candidate.path.clone(),
+        });
+    }
+    // Windows executables are never run elsewhere, so a small one there is not our placeholder
+    let placeholder = candidates.iter().find_map(|candidate| {
+        let windows_exe = candidate
+            .path
+            .extension()
+            .is_some_and(|extension| extension == "exe");
+        candidate
+            .size
+            .filter(|size| *size < 1024 && (cfg!(windows) || !windows_exe))
+            .map(|size| diagnostics::BinaryStatus::Placeholder {
+                path: candidate.path.clone(),
+                size,
+            })
+    });
+    Ok(placeholder.unwrap_or(diagnostics::BinaryStatus::Missing))
+}
+
+/// Scan for the backend executable again, ignoring the report from the last startup
+/// The new report replaces the stored one; `selected` is the executable the scan found, which
+/// the next start installs into the app data directory
+fn rediscover_backend(app: &tauri::AppHandle) -> Result<DiscoveryReport, String> {
+    info!("Re-running backend discovery");
+    let (manifest, candidates) = scan_backend_candidates(app)?;
+    let selected = if config::current(app).backend_mode == config::LaunchMode::Python {
+        None
+    } else {
+        candidates
+            .iter()
+            .skip(1)
+            .find(|candidate| usable_backend_exe(candidate, manifest.is_some()))
+            .map(|candidate| candidate.path.clone())
+    };
+    match &selected {
+        Some(path) => info!("Discovery found the backend executable at {:?}", path),
+        None => info!("Discovery found no backend executable"),
+    }
+
+    let report = DiscoveryReport {
+        candidates,
+        selected,
+        manifest: manifest.map(|manifest| manifest.source),
+    };
+    record_startup_info(app, |info| info.discovery = report.clone());
+    Ok(report)
+}
+
+/// Discover the backend (bundled executable or Python source tree) and start it against `db_path`
+/// Blocks until the backend has been started; the child process is stored in app state
+fn launch_backend(app_handle: &tauri::AppHandle, db_path: &Path) -> Result<(), String> {
+    let attempt_id = logging::begin_attempt();
+    info!("Backend startup attempt {}", attempt_id);
+    if let Some(timeline) = app_handle.try_state::<StartupTimeline>() {
+        timeline.reset();
+    }
+
+    // Initialize database on startup - don't fail if this doesn't work
+    let db_timer = PhaseTimer::start("initialize_database");
+    // A read-only database must not be created or migrated
+    let db_init = if config::current(app_handle).read_only {
+        info!("Read-only mode, skipping database initialization");
+        Ok(())
+    } else {
+        initialize_database(db_path)
+    };
+    if let Err(e) = db_init {
+        // An unreachable data directory would make every later step hang, so stop here
+        if let Some(backend_error @ BackendError::StorageUnavailable { .. }) =
+            e.downcast_ref::<BackendError>()
+        {
+            error!("{}", backend_error);
+            events::emit(
+                app_handle,
+                "backend://storage_unavailable",
+                backend_error.clone(),
+            );
+            return Err(backend_error.to_string());
+        }
+        if fail_fast() {
+            return Err(format!("Database initialization failed: {}", e));
+        }
+        eprintln!("Database initialization warning: {}", e);
+        // Don't fail startup if database init fails - it will be created on first use
+    }
+    db_timer.finish(app_handle);
+
+    // Get app data directory for storing backend executable
+    let app_data_dir = storage::app_data_dir(app_handle)?;
+
+    // Path where backend-server should be stored in app data directory
+    let installed_backend_path = app_data_dir.join(BACKEND_EXE_NAME);
+
+    // First, try to find bundled backend executable (for release builds)
+    let exe_path = std::env::current_exe().unwrap_or_default();
+    let exe_dir = exe_path.parent().unwrap_or(std::path::Path::new("."));
+
+    info!("Looking for bundled backend executable...");
+    // Stat-heavy: on contended disks this phase is dominated by I/O wait
+    let discovery_timer = PhaseTimer::start_measured(app_handle, "discovery", CpuScope::Process);
+    info!("Executable path: {:?}", exe_path);
+    info!("Executable directory: {:?}", exe_dir);
+
+    // A packager-provided manifest names the executable outright; otherwise search for it
+    let manifest = manifest::load(app_handle)?;
+    let possible_exe_paths = match &manifest {
+        Some(manifest) => {
+            info!(
+                "Using backend executable from {:?}: {:?} (version {})",
+                manifest.source,
+                manifest.executable,
+                manifest.version.as_deref().unwrap_or("unknown")
+            );
+            manifest_exe_paths(app_handle, manifest)
+        }
+        None => heuristic_exe_paths(app_handle, exe_dir),
+    };
+
+    // Log all paths being checked and keep them for the discovery report
+    info!("Checking the following paths for backend executable:");
+    // Stat all candidates concurrently (slow on network filesystems); order is preserved
+    let all_paths: Vec<PathBuf> = std::iter::once(installed_backend_path.clone())
+        .chain(possible_exe_paths.iter().cloned())
+        .collect();
+    let candidates = diagnostics::probe_candidates(&all_paths);
+    for candidate in &candidates {
+        info!(
+            "  {:?} - {}",
+            candidate.path,
+            if candidate.exists {
+                "EXISTS"
+            } else {
+                "not found"
+            }
+        );
+    }
+    // The installed path was probed first
+    let mut exe_candidates = candidates.clone();
+    let installed_candidate = exe_candidates.remove(0);
+    record_startup_info(app_handle, |info| {
+        info.discovery = DiscoveryReport {
+            candidates,
+            selected: None,
+            manifest: manifest.as_ref().map(|manifest| manifest.source.clone()),
+        }
+    });
+
+    let launch_mode = config::current(app_handle).backend_mode;
+    if launch_mode == config::LaunchMode::Python {
+        info!("backend_mode = \"python\", ignoring backend executables");
+    }
+
+    // Check if backend is already installed in app data directory
+    // Not a placeholder, and not a stale copy of an executable the manifest pins by hash
+    let backend_installed = launch_mode != config::LaunchMode::Python
+        && installed_candidate.size.is_some_and(|size| size >= 1024)
+        && manifest
+            .as_ref()
+            .is_none_or(|manifest| manifest.matches(&installed_backend_path));
+
+    if backend_installed {
+        info!("Backend already installed at: {:?}", installed_backend_path);
+    }
+
+    // Find the first existing executable from bundled/source locations, in priority order
+    let bundled_exe = exe_candidates
+        .iter()
+        .filter(|_| launch_mode != config::LaunchMode::Python)
+        .find(|candidate| usable_backend_exe(candidate, manifest.is_some()))
+        .map(|candidate| candidate.path.clone());
+    discovery_timer.finish(app_handle);
+
+    // Determine which backend executable to use
+    let install_timer = PhaseTimer::start("install_backend");
+    // Priority: 1. Installed in app data dir (if up to date), 2. Bundled/source
+    let backend_to_use: Option<PathBuf> = if backend_installed {
+        // Check if bundled version is newer (compare file sizes as simple heuristic,
+        // or always prefer installed version for consistency)
+        if let Some(ref bundled) = bundled_exe {
             let bundled_size = std::fs::metadata(bundled).map(|m| m.len()).unwrap_or(0);
-            let installed_size = std::fs::metadata(&installed_backend_path).map(|m| m.len()).unwrap_or(0);
+            let installed_size = std::fs::metadata(&installed_backend_path)
+                .map(|m| m.len())
+                .unwrap_or(0);
 
             // If bundled is significantly different (more than 1KB difference), update installed version
             if bundled_size.abs_diff(installed_size) > 1024 {
-              info!("Bundled backend differs from installed version, updating...");
-              match std::fs::copy(bundled, &installed_backend_path) {
-                Ok(_) => {
-                  info!("Updated backend at: {:?}", installed_backend_path);
-                  // Make executable on Unix
-                  #[cfg(unix)]
-                  {
-                    use std::os::unix::fs::PermissionsExt;
-                    if let Ok(metadata) = std::fs::metadata(&installed_backend_path) {
-                      let mut perms = metadata.permissions();
-                      perms.set_mode(0o755);
-                      let _ = std::fs::set_permissions(&installed_backend_path, perms);
+                info!("Bundled backend differs from installed version, updating...");
+                match std::fs::copy(bundled, &installed_backend_path) {
+                    Ok(_) => {
+                        info!("Updated backend at: {:?}", installed_backend_path);
+                        // Make executable on Unix
+                        #[cfg(unix)]
+                        {
+                            use std::os::unix::fs::PermissionsExt;
+                            if let Ok(metadata) = std::fs::metadata(&installed_backend_path) {
+                                let mut perms = metadata.permissions();
+                                perms.set_mode(0o755);
+                                let _ = std::fs::set_permissions(&installed_backend_path, perms);
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        warn!(
+                            "Failed to update backend: {}, using existing installation",
+                            e
+                        );
                     }
-                  }
-                }
-                Err(e) => {
-                  warn!("Failed to update backend: {}, using existing installation", e);
                 }
-              }
             }
-          }
-          Some(installed_backend_path.clone())
-        } else if let Some(ref bundled) = bundled_exe {
-          // Install bundled backend to app data directory
-          info!("Installing backend to app data directory: {:?}", installed_backend_path);
-          match std::fs::copy(bundled, &installed_backend_path) {
+        }
+        Some(installed_backend_path.clone())
+    } else if let Some(ref bundled) = bundled_exe {
+        // Install bundled backend to app data directory
+        info!(
+            "Installing backend to app data directory: {:?}",
+            installed_backend_path
+        );
+        match std::fs::copy(bundled, &installed_backend_path) {
             Ok(bytes) => {
-              info!("Copied {} bytes to {:?}", bytes, installed_backend_path);
-              // Make executable on Unix
-              #[cfg(unix)]
-              {
-                use std::os::unix::fs::PermissionsExt;
-                if let Ok(metadata) = std::fs::metadata(&installed_backend_path) {
-                  let mut perms = metadata.permissions();
-                  perms.set_mode(0o755);
-                  if let Err(e) = std::fs::set_permissions(&installed_backend_path, perms) {
-                    warn!("Failed to set executable permissions: {}", e);
-                  } else {
-                    info!("Set executable permissions on backend");
-                  }
+                info!("Copied {} bytes to {:?}", bytes, installed_backend_path);
+                // Make executable on Unix
+                #[cfg(unix)]
+                {
+                    use std::os::unix::fs::PermissionsExt;
+                    if let Ok(metadata) = std::fs::metadata(&installed_backend_path) {
+                        let mut perms = metadata.permissions();
+                        perms.set_mode(0o755);
+                        if let Err(e) = std::fs::set_permissions(&installed_backend_path, perms) {
+                            warn!("Failed to set executable permissions: {}", e);
+                        } else {
+                            info!("Set executable permissions on backend");
+                        }
+                    }
                 }
-              }
-              Some(installed_backend_path.clone())
+                Some(installed_backend_path.clone())
             }
             Err(e) => {
-              error!("Failed to install backend to app data directory: {}", e);
-              // Fall back to using bundled directly
-              Some(bundled.clone())
+                error!("Failed to install backend to app data directory: {}", e);
+                // Fall back to using bundled directly
+                Some(bundled.clone())
             }
-          }
-        } else {
-          None
-        };
+        }
+    } else {
+        None
+    };
+    install_timer.finish(app_handle);
+    record_startup_info(app_handle, |info| {
+        info.discovery.selected = backend_to_use.clone()
+    });
 
-        // If we have a backend executable to use, start it
-        if let Some(exe_path) = backend_to_use {
-          info!("Using backend executable: {:?}", exe_path);
+    // If we have a backend executable to use, start it
+    if let Some(exe_path) = backend_to_use {
+        info!("Using backend executable: {:?}", exe_path);
 
-          // For backend in app data directory, use app data dir as working directory
-          let backend_working_dir = exe_path.parent()
+        // For backend in app data directory, use app data dir as working directory
+        let backend_working_dir = exe_path
+            .parent()
             .map(|p| p.to_path_buf())
             .unwrap_or_else(|| app_data_dir.clone());
 
-          info!("Backend working directory: {:?}", backend_working_dir);
+        info!("Backend working directory: {:?}", backend_working_dir);
 
-          match start_backend_server(&app_handle, &backend_working_dir, &db_path_clone) {
+        match start_backend_server(app_handle, &backend_working_dir, db_path, Some(&exe_path)) {
             Ok(child) => {
-              // Store process in app state
-              if let Some(state) = app_handle.try_state::<Mutex<Option<Child>>>() {
-                if let Ok(mut process) = state.lock() {
-                  *process = Some(child);
-                  info!("Backend server started successfully from: {:?}", exe_path);
-                } else {
-                  warn!("Could not store backend process in app state");
-                }
-              }
+                store_backend_process(app_handle, child);
+                info!("Backend server started successfully from: {:?}", exe_path);
+                Ok(())
             }
             Err(e) => {
-              error!("Failed to start backend server: {}", e);
-              error!("Backend server not started. API calls will fail.");
+                error!("Failed to start backend server: {}", e);
+                error!("Backend server not started. API calls will fail.");
+                Err(e.to_string())
             }
-          }
-        } else {
-          // Fallback: Find backend directory (for development)
-          info!("Bundled backend executable not found, looking for backend directory...");
+        }
+    } else {
+        // Fallback: Find backend directory (for development)
+        info!("Bundled backend executable not found, looking for backend directory...");
 
-          let mut possible_backend_paths: Vec<PathBuf> = vec![
+        let mut possible_backend_paths: Vec<PathBuf> = vec![
             exe_dir.join("backend"),
             exe_dir.join("../../backend"),
-            exe_dir.join("../../../backend"),  // From target/release/
+            exe_dir.join("../../../backend"), // From target/release/
             exe_dir.join("../../../../backend"), // From target/release/ if deeper
-          ];
+        ];
 
-          // Add parent directory paths
-          if let Some(parent) = exe_dir.parent() {
+        // Add parent directory paths
+        if let Some(parent) = exe_dir.parent() {
             possible_backend_paths.push(parent.join("backend"));
             if let Some(grandparent) = parent.parent() {
-              possible_backend_paths.push(grandparent.join("backend"));
-              if let Some(ggparent) = grandparent.parent() {
-                possible_backend_paths.push(ggparent.join("backend"));
-              }
+                possible_backend_paths.push(grandparent.join("backend"));
+                if let Some(ggparent) = grandparent.parent() {
+                    possible_backend_paths.push(ggparent.join("backend"));
+                }
             }
-          }
+        }
 
-          // Also try absolute path from project root (if we're in development)
-          if let Ok(current_dir) = std::env::current_dir() {
+        // Also try absolute path from project root (if we're in development)
+        if let Ok(current_dir) = std::env::current_dir() {
             possible_backend_paths.push(current_dir.join("backend"));
             if let Some(parent) = current_dir.parent() {
-              possible_backend_paths.push(parent.join("backend"));
+                possible_backend_paths.push(parent.join("backend"));
             }
-          }
+        }
 
-          // Check common project locations (useful when binary is run from Downloads or elsewhere)
-          #[cfg(target_os = "linux")]
-          {
+        // Check common project locations (useful when binary is run from Downloads or elsewhere)
+        #[cfg(target_os = "linux")]
+        {
             if let Ok(home) = std::env::var("HOME") {
-              let home_path = PathBuf::from(&home);
-              let common_project_locations = vec![
-                home_path.join("Dokumente").join("GitHub").join("Budget-Planer").join("backend"),
-                home_path.join("Documents").join("GitHub").join("Budget-Planer").join("backend"),
-                home_path.join("projects").join("Budget-Planer").join("backend"),
-                home_path.join("Projects").join("Budget-Planer").join("backend"),
-                home_path.join("dev").join("Budget-Planer").join("backend"),
-                home_path.join("Dev").join("Budget-Planer").join("backend"),
-                PathBuf::from("/home").join("firstpick").join("Dokumente").join("GitHub").join("Budget-Planer").join("backend"),
-              ];
-
-              for project_path in common_project_locations {
-                if project_path.exists() {
-                  info!("Found potential project directory: {:?}", project_path);
-                  possible_backend_paths.push(project_path.clone());
-                  // Also check the dist subdirectory
-                  let dist_path = project_path.join("dist");
-                  if dist_path.exists() {
-                    possible_backend_paths.push(dist_path);
-                  }
+                let home_path = PathBuf::from(&home);
+                let common_project_locations = vec![
+                    home_path
+                        .join("Dokumente")
+                        .join("GitHub")
+                        .join("Budget-Planer")
+                        .join("backend"),
+                    home_path
+                        .join("Documents")
+                        .join("GitHub")
+                        .join("Budget-Planer")
+                        .join("backend"),
+                    home_path
+                        .join("projects")
+                        .join("Budget-Planer")
+                        .join("backend"),
+                    home_path
+                        .join("Projects")
+                        .join("Budget-Planer")
+                        .join("backend"),
+                    home_path.join("dev").join("Budget-Planer").join("backend"),
+                    home_path.join("Dev").join("Budget-Planer").join("backend"),
+                    PathBuf::from("/home")
+                        .join("firstpick")
+                        .join("Dokumente")
+                        .join("GitHub")
+                        .join("Budget-Planer")
+                        .join("backend"),
+                ];
+
+                for project_path in common_project_locations {
+                    if project_path.exists() {
+                        info!("Found potential project directory: {:?}", project_path);
+                        possible_backend_paths.push(project_path.clone());
+                        // Also check the dist subdirectory
+                        let dist_path = project_path.join("dist");
+                        if dist_path.exists() {
+                            possible_backend_paths.push(dist_path);
+                        }
+                    }
                 }
-              }
             }
 
             // Check BACKEND_PATH environment variable
             if let Ok(backend_path) = std::env::var("BACKEND_PATH") {
-              let backend_path_buf = PathBuf::from(&backend_path);
-              if backend_path_buf.exists() {
-                info!("Using backend from BACKEND_PATH: {:?}", backend_path_buf);
-                possible_backend_paths.push(backend_path_buf);
-              }
+                let backend_path_buf = PathBuf::from(&backend_path);
+                if backend_path_buf.exists() {
+                    info!("Using backend from BACKEND_PATH: {:?}", backend_path_buf);
+                    possible_backend_paths.push(backend_path_buf);
+                }
             }
-          }
+        }
 
-          let mut backend_path: Option<PathBuf> = None;
-          let mut backend_exe_path: Option<PathBuf> = None;
+        // The configured discovery roots also bound the source tree fallback
+        possible_backend_paths.retain(|path| discovery_allowed(app_handle, path));
 
-          for path in &possible_backend_paths {
+        let mut backend_path: Option<PathBuf> = None;
+        let mut backend_exe_path: Option<PathBuf> = None;
+
+        for path in &possible_backend_paths {
             // First check if this path itself is the executable
-            let is_backend_server = path.file_name().and_then(|n| n.to_str()).map(|s| s == "backend-server").unwrap_or(false);
+            let is_backend_server = path
+                .file_name()
+                .and_then(|n| n.to_str())
+                .map(|s| s == "backend-server")
+                .unwrap_or(false);
             if is_backend_server
-              && path.exists()
-              && std::fs::metadata(path).map(|m| m.len() >= 1024).unwrap_or(false)
+                && path.exists()
+                && std::fs::metadata(path)
+                    .map(|m| m.len() >= 1024)
+                    .unwrap_or(false)
             {
-              backend_exe_path = Some(path.clone());
-              info!("Found backend executable directly: {:?}", backend_exe_path);
-              break;
+                backend_exe_path = Some(path.clone());
+                info!("Found backend executable directly: {:?}", backend_exe_path);
+                break;
             }
 
             // Check if this is already a dist directory with the executable
             let exe_in_dist = path.join("backend-server");
             if exe_in_dist.exists()
-              && std::fs::metadata(&exe_in_dist).map(|m| m.len() >= 1024).unwrap_or(false)
+                && std::fs::metadata(&exe_in_dist)
+                    .map(|m| m.len() >= 1024)
+                    .unwrap_or(false)
             {
-              backend_exe_path = Some(exe_in_dist);
-              info!("Found backend executable in dist directory: {:?}", backend_exe_path);
-              break;
+                backend_exe_path = Some(exe_in_dist);
+                info!(
+                    "Found backend executable in dist directory: {:?}",
+                    backend_exe_path
+                );
+                break;
             }
 
             // Check if this is a backend directory (has manage.py)
             let manage_py = path.join("manage.py");
             if manage_py.exists() {
-              backend_path = Some(path.clone());
-              info!("Found backend directory at: {:?}", path);
-
-              // Also check if there's a dist subdirectory with the executable
-              let dist_exe = path.join("dist").join("backend-server");
-              if dist_exe.exists()
-                && std::fs::metadata(&dist_exe).map(|m| m.len() >= 1024).unwrap_or(false)
-              {
-                backend_exe_path = Some(dist_exe);
-                info!("Found backend executable in backend/dist: {:?}", backend_exe_path);
-                break;
-              }
+                backend_path = Some(path.clone());
+                info!("Found backend directory at: {:?}", path);
+
+                // Also check if there's a dist subdirectory with the executable
+                let dist_exe = path.join("dist").join("backend-server");
+                if dist_exe.exists()
+                    && std::fs::metadata(&dist_exe)
+                        .map(|m| m.len() >= 1024)
+                        .unwrap_or(false)
+                {
+                    backend_exe_path = Some(dist_exe);
+                    info!(
+                        "Found backend executable in backend/dist: {:?}",
+                        backend_exe_path
+                    );
+                    break;
+                }
             }
-          }
+        }
 
-          // If we found the executable directly, use it
-          if let Some(exe_path) = backend_exe_path {
+        // If we found the executable directly, use it
+        if let Some(exe_path) =
+            backend_exe_path.filter(|_| launch_mode != config::LaunchMode::Python)
+        {
             info!("Using backend executable: {:?}", exe_path);
-            // Get the backend directory (parent of dist, or parent of executable)
-            let backend_dir = if exe_path.parent().and_then(|p| p.file_name()).map(|n| n == "dist").unwrap_or(false) {
-              exe_path.parent().and_then(|p| p.parent()).map_or_else(|| PathBuf::from("."), Path::to_path_buf)
-            } else {
-              exe_path.parent().map_or_else(|| PathBuf::from("."), Path::to_path_buf)
-            };
+            let backend_dir = backend_dir_for_executable(&exe_path);
+            info!("Backend working directory: {:?}", backend_dir);
 
-            match start_backend_server(&app_handle, &backend_dir, &db_path_clone) {
-              Ok(child) => {
-                // Store process in app state
-                if let Some(state) = app_handle.try_state::<Mutex<Option<Child>>>() {
-                  if let Ok(mut process) = state.lock() {
-                    *process = Some(child);
+            match start_backend_server(app_handle, &backend_dir, db_path, Some(&exe_path)) {
+                Ok(child) => {
+                    store_backend_process(app_handle, child);
                     info!("Backend server started successfully using found executable");
-                  } else {
-                    warn!("Could not store backend process in app state");
-                  }
+                    Ok(())
+                }
+                Err(e) => {
+                    error!(
+                        "Failed to start backend server with found executable: {}",
+                        e
+                    );
+                    error!("Backend server not started. API calls will fail.");
+                    Err(e.to_string())
                 }
-              }
-              Err(e) => {
-                error!("Failed to start backend server with found executable: {}", e);
-                error!("Backend server not started. API calls will fail.");
-              }
             }
-          }
-          // Start backend server if found - don't fail if this doesn't work
-          else if let Some(backend_path) = backend_path {
-            match start_backend_server(&app_handle, &backend_path, &db_path_clone) {
-              Ok(child) => {
-                // Store process in app state
-                if let Some(state) = app_handle.try_state::<Mutex<Option<Child>>>() {
-                  if let Ok(mut process) = state.lock() {
-                    *process = Some(child);
+        }
+        // Never fall back to Python when only the bundled executable was asked for
+        else if launch_mode == config::LaunchMode::Bundled {
+            let error_msg =
+                "No backend executable found (backend_mode = \"bundled\" does not fall back to Python)"
+                    .to_string();
+            error!("{}", error_msg);
+            Err(error_msg)
+        }
+        // Start backend server if found - don't fail if this doesn't work
+        else if let Some(backend_path) = backend_path {
+            match start_backend_server(app_handle, &backend_path, db_path, None) {
+                Ok(child) => {
+                    store_backend_process(app_handle, child);
                     info!("Backend server started successfully");
-                  } else {
-                    warn!("Could not store backend process in app state");
-                  }
+                    Ok(())
+                }
+                Err(e) => {
+                    error!("Failed to start backend server: {}", e);
+                    error!("Backend server not started. API calls will fail.");
+                    error!("");
+                    error!("To fix this issue:");
+                    error!(
+                        "1. Make sure Python 3.10+ is installed (https://www.python.org/downloads/)"
+                    );
+                    error!("2. Run setup-backend.ps1 from the project root directory");
+                    error!(
+                        "3. Make sure the backend directory exists at: {:?}",
+                        backend_path
+                    );
+                    Err(e.to_string())
                 }
-              }
-              Err(e) => {
-                error!("Failed to start backend server: {}", e);
-                error!("Backend server not started. API calls will fail.");
-                error!("");
-                error!("To fix this issue:");
-                error!("1. Make sure Python 3.10+ is installed (https://www.python.org/downloads/)");
-                error!("2. Run setup-backend.ps1 from the project root directory");
-                error!("3. Make sure the backend directory exists at: {:?}", backend_path);
-              }
             }
-          } else {
+        } else {
             error!("Backend directory not found. Backend server not started.");
             error!("Searched in the following locations:");
             for path in &possible_backend_paths {
-              error!("  - {:?}", path);
+                error!("  - {:?}", path);
             }
             error!("");
             error!("To fix this issue:");
             error!("1. Make sure the backend directory exists");
-            error!("2. If this is a packaged app, the backend needs to be bundled with the application");
+            error!(
+                "2. If this is a packaged app, the backend needs to be bundled with the application"
+            );
             error!("3. For development, make sure you're running from the project root");
-          }
+            Err("Backend directory not found. Backend server not started.".to_string())
         }
-      });
+    }
+}
+
+/// Database path the backend is started against, kept so restarts reuse it
+pub struct ActiveDatabase(pub Mutex<PathBuf>);
+
+/// Stop the managed backend process, if any
+fn stop_backend(app: &tauri::AppHandle) {
+    suspend::resume_before_stop(app);
+    if let Some(state) = app.try_state::<Mutex<Option<Child>>>()
+        && let Ok(mut process) = state.lock()
+        && let Some(mut child) = process.take()
+    {
+        kill_backend_process(&mut child);
+    }
+}
+
+/// Stop the backend and start it again against the active database
+/// Blocks until the new backend is running, so call it off the main thread
+/// Restarts requested while one is running are coalesced into a single follow-up restart
+fn restart_backend(app: &tauri::AppHandle) -> Result<(), String> {
+    restart_backend_for(app, restarts::RestartReason::Manual)
+}
 
-      eprintln!("Tauri app setup completed successfully (backend starting in background)");
-      Ok(())
+/// `restart_backend`, counted under `reason` in the session's restart stats
+fn restart_backend_for(
+    app: &tauri::AppHandle,
+    reason: restarts::RestartReason,
+) -> Result<(), String> {
+    run_exclusive(app, || {
+        restarts::record(app, reason);
+        restart_now(app)
     })
-    .on_window_event(|app, event| {
-      // Cleanup backend process when window closes - non-blocking
-      if let tauri::WindowEvent::CloseRequested { .. } = event {
-        info!("Window close requested, initiating backend cleanup...");
+}
 
-        // Get the process and kill it in background to avoid blocking window close
-        let app_handle = app.app_handle().clone();
-        std::thread::spawn(move || {
-          if let Some(state) = app_handle.try_state::<Mutex<Option<Child>>>() {
-            // Use try_lock first to avoid blocking
-            if let Ok(mut process) = state.try_lock() {
-              if let Some(mut child) = process.take() {
-                kill_backend_process(&mut child);
-              }
+fn restart_now(app: &tauri::AppHandle) -> Result<(), String> {
+    info!("Restarting backend server...");
+    stop_backend(app);
+    let db_path = active_db_path(app).ok_or("Active database path is not known")?;
+    launch_backend(app, &db_path)
+}
+
+/// Returned to restart requests that were folded into one already running
+const RESTART_IN_PROGRESS: &str = "Backend restart already in progress";
+
+/// Serializes backend start/stop sequences so overlapping ones can't race over the port
+#[derive(Default)]
+struct LaunchGate {
+    running: std::sync::atomic::AtomicBool,
+    /// A restart was requested while a sequence was running
+    pending: std::sync::atomic::AtomicBool,
+}
+
+impl LaunchGate {
+    /// Run a start/stop sequence unless one is already running
+    /// A request arriving meanwhile gets `RESTART_IN_PROGRESS` and is applied as one more
+    /// `restart` once the current sequence finishes, however many requests arrived
+    fn run(
+        &self,
+        sequence: impl FnOnce() -> Result<(), String>,
+        mut restart: impl FnMut() -> Result<(), String>,
+    ) -> Result<(), String> {
+        use std::sync::atomic::Ordering;
+
+        if self.running.swap(true, Ordering::SeqCst) {
+            self.pending.store(true, Ordering::SeqCst);
+            info!("{}, coalescing this request", RESTART_IN_PROGRESS);
+            return Err(RESTART_IN_PROGRESS.to_string());
+        }
+        self.pending.store(false, Ordering::SeqCst);
+        let mut result = sequence();
+        loop {
+            if self.pending.swap(false, Ordering::SeqCst) {
+                info!("Applying the restart requested while the backend was starting");
+                result = restart();
+                continue;
+            }
+            self.running.store(false, Ordering::SeqCst);
+            // A request that saw `running` just before it was cleared must not be lost
+            if !self.pending.load(Ordering::SeqCst) || self.running.swap(true, Ordering::SeqCst) {
+                return result;
+            }
+        }
+    }
+}
+
+/// Run a start/stop sequence through the app's `LaunchGate`
+/// Requests made while it runs are coalesced into one more restart, so state changed by their
+/// callers (profile, mode) takes effect
+fn run_exclusive(
+    app: &tauri::AppHandle,
+    sequence: impl FnOnce() -> Result<(), String>,
+) -> Result<(), String> {
+    match app.try_state::<LaunchGate>() {
+        Some(gate) => gate.run(sequence, || restart_now(app)),
+        None => sequence(),
+    }
+}
+
+/// How long closing the window waits for the killed backend to exit
+const WINDOW_CLOSE_KILL_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(2);
+
+/// Stop the backend and free its port, blocking until done
+/// Used on app exit so the backend never outlives the app
+fn shutdown_backend(app: &tauri::AppHandle) {
+    suspend::resume_before_stop(app);
+    if let Some(state) = app.try_state::<Mutex<Option<Child>>>()
+        && let Ok(mut process) = state.lock()
+        && let Some(mut child) = process.take()
+    {
+        kill_backend_process(&mut child);
+        // Wait a moment to ensure process is killed
+        std::thread::sleep(std::time::Duration::from_millis(200));
+    }
+    // Also kill any orphaned backend still holding the port as a fallback
+    if config::current(app).manage_port {
+        let _ = port::kill_process_on_port(port::current_port(app));
+    }
+}
+
+/// Database path the backend is (or will be) started against
+fn active_db_path(app: &tauri::AppHandle) -> Option<PathBuf> {
+    app.try_state::<ActiveDatabase>()
+        .and_then(|state| state.0.lock().ok().map(|path| path.clone()))
+}
+
+/// Point the backend at a different database; takes effect on the next (re)start
+fn set_active_db_path(app: &tauri::AppHandle, db_path: PathBuf) {
+    if let Some(state) = app.try_state::<ActiveDatabase>()
+        && let Ok(mut active) = state.0.lock()
+    {
+        *active = db_path;
+    }
+}
+
+/// Whether a backend process is currently held in app state
+fn backend_started(app: &tauri::AppHandle) -> bool {
+    app.try_state::<Mutex<Option<Child>>>()
+        .and_then(|state| state.lock().ok().map(|process| process.is_some()))
+        .unwrap_or(false)
+}
+
+/// Whether the backend process held in app state is still running
+fn backend_alive(app: &tauri::AppHandle) -> bool {
+    app.try_state::<Mutex<Option<Child>>>()
+        .and_then(|state| {
+            state.lock().ok().map(|mut process| {
+                process
+                    .as_mut()
+                    .is_some_and(|child| matches!(child.try_wait(), Ok(None)))
+            })
+        })
+        .unwrap_or(false)
+}
+
+/// PID of the backend process held in app state, if any
+fn backend_pid(app: &tauri::AppHandle) -> Option<u32> {
+    app.try_state::<Mutex<Option<Child>>>().and_then(|state| {
+        state
+            .lock()
+            .ok()
+            .and_then(|process| process.as_ref().map(Child::id))
+    })
+}
+
+/// Store the running backend process in app state so it can be stopped later
+fn store_backend_process(app: &tauri::AppHandle, child: Child) {
+    if let Some(state) = app.try_state::<Mutex<Option<Child>>>() {
+        if let Ok(mut process) = state.lock() {
+            *process = Some(child);
+            known_good::save_when_healthy(app);
+        } else {
+            warn!("Could not store backend process in app state");
+        }
+    }
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+    // Fix grey window issue on Linux by setting WebKit environment variables
+    // This disables problematic rendering features that cause EGL errors
+    #[cfg(target_os = "linux")]
+    {
+        use std::env;
+        // Disable DMABUF renderer to fix EGL_BAD_PARAMETER errors
+        // Safety: Setting environment variables is safe in single-threaded context before Tauri starts
+        if env::var("WEBKIT_DISABLE_DMABUF_RENDERER").is_err() {
+            unsafe {
+                env::set_var("WEBKIT_DISABLE_DMABUF_RENDERER", "1");
+            }
+        }
+        // Disable compositing mode to avoid rendering issues
+        if env::var("WEBKIT_DISABLE_COMPOSITING_MODE").is_err() {
+            unsafe {
+                env::set_var("WEBKIT_DISABLE_COMPOSITING_MODE", "1");
+            }
+        }
+        // Force X11 backend if on Wayland (more stable for WebKit)
+        if env::var("GDK_BACKEND").is_err() && env::var("WAYLAND_DISPLAY").is_ok() {
+            unsafe {
+                env::set_var("GDK_BACKEND", "x11");
+            }
+        }
+    }
+
+    // Store backend process handle in app state
+    let backend_process: Mutex<Option<Child>> = Mutex::new(None);
+
+    tauri::Builder::default()
+        .manage(backend_process)
+        .manage(StartupTimeline::default())
+        .manage(health::LastHealth::default())
+        .manage(suspend::BackendSuspended::default())
+        .manage(clean_session::CleanSession::default())
+        .manage(health::StartupParamsState::default())
+        .manage(setup::SetupState::default())
+        .manage(diagnostics::BackendSpawn::default())
+        .manage(LaunchGate::default())
+        .manage(health_client::SharedHealthClient::default())
+        .manage(restarts::RestartCounter::default())
+        .manage(events::ProgressWindow::default())
+        .manage(BackendPort(Mutex::new(DEFAULT_BACKEND_PORT)))
+        .manage(Mutex::new(StartupInfo::default()))
+        .invoke_handler(tauri::generate_handler![
+            commands::get_startup_timeline,
+            commands::get_env_report,
+            commands::set_debug,
+            commands::get_backend_port,
+            commands::get_backend_scheme,
+            commands::resolve_port_conflict,
+            commands::set_log_level,
+            commands::switch_profile,
+            commands::has_pending_migrations,
+            commands::run_migrations,
+            commands::use_database,
+            commands::list_backups,
+            commands::delete_backup,
+            commands::ping_backend,
+            commands::quit_app,
+            commands::get_effective_config,
+            commands::reload_config,
+            commands::run_management_command,
+            commands::get_app_logs,
+            commands::get_discovery_report,
+            commands::reconnect_backend,
+            commands::export_support_bundle,
+            commands::check_environment,
+            commands::get_backend_cpu,
+            commands::get_last_health,
+            commands::suspend_backend,
+            commands::resume_backend,
+            commands::verify_backend_signature,
+            commands::start_clean_session,
+            commands::end_clean_session,
+            commands::set_startup_params,
+            commands::cancel_setup,
+            commands::probe_paths,
+            commands::wal_checkpoint,
+            commands::get_backend_process_info,
+            commands::cache_dependencies,
+            commands::inspect_database,
+            commands::backend_check,
+            commands::set_read_only,
+            commands::get_migration_history,
+            commands::set_backend_mode,
+            commands::get_storage_usage,
+            commands::rotate_secret_key,
+            commands::clear_backend_port,
+            commands::rediscover_backend,
+            commands::set_backend_port,
+            commands::get_restart_count,
+            commands::verify_connection,
+            commands::get_backend_command,
+            commands::restore_last_good_config,
+            commands::set_progress_window,
+            commands::benchmark_backend,
+            commands::backend_binary_status,
+            commands::list_profiles,
+            commands::delete_profile,
+        ])
+        .setup(move |app| {
+            // Enable logging in both debug and release modes for troubleshooting
+            logging::init(app);
+            logging::install_panic_hook();
+            storage::report_portable_mode(app.handle());
+
+            // Load user config before anything reads it
+            let (config, config_errors) = config::load(app.handle());
+            config::report_errors(app.handle(), &config_errors);
+            logging::apply_config_level(&config.log_level);
+            port::set_current_port(app.handle(), config.backend_port);
+            app.manage(Mutex::new(config));
+
+            // Get app data directory for database; an unavailable one falls back to a reported,
+            // writable location
+            // The database file comes from the profile that was active on the last run
+            let data_dir = storage::app_data_dir(app.handle()).unwrap_or_else(|e| {
+                error!("{}", e);
+                PathBuf::from(".")
+            });
+            let db_path = profiles::resolve_db_path(&data_dir, &config::current(app.handle()));
+
+            app.manage(ActiveDatabase(Mutex::new(db_path.clone())));
+            backups::start_scheduler(app.handle().clone());
+            wal::start_scheduler(app.handle().clone());
+            if fail_fast() {
+                info!(
+                    "{} is set: short health timeouts, no spawn retries or automatic restarts",
+                    FAIL_FAST_ENV
+                );
+                if let Err(e) = health::set_startup_params(app.handle(), 5_000, 50, 500) {
+                    warn!("Could not shorten health timeouts: {}", e);
+                }
             } else {
-              // If lock is held, wait briefly then try again
-              std::thread::sleep(std::time::Duration::from_millis(50));
-              if let Ok(mut process) = state.lock() {
-                if let Some(mut child) = process.take() {
-                  kill_backend_process(&mut child);
+                resume::start_watcher(app.handle().clone());
+                watchdog::start(app.handle().clone());
+            }
+
+            // Move all blocking operations to a background thread to prevent UI hang
+            let app_handle = app.handle().clone();
+            let db_path_clone = db_path.clone();
+            std::thread::spawn(move || {
+                temp_cleanup::remove_stale_extractions();
+                // Failures are logged by launch_backend; the app keeps running without a backend,
+                // so tell the UI instead of leaving it waiting
+                // A panic is only caught in builds that unwind (debug); release builds abort, and
+                // the panic hook's log entry is what's left of it
+                let launched = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+                    run_exclusive(&app_handle, || launch_backend(&app_handle, &db_path_clone))
+                }));
+                let failure = match launched {
+                    Ok(Ok(())) => None,
+                    Ok(Err(message)) => Some(("launch_failed", message)),
+                    Err(_) => Some((
+                        "panicked",
+                        "The backend startup thread crashed, see the app log for details"
+                            .to_string(),
+                    )),
+                };
+                if let Some((code, message)) = failure {
+                    error!("Backend startup failed ({}): {}", code, message);
+                    events::emit(
+                        &app_handle,
+                        "backend://startup_failed",
+                        serde_json::json!({
+                            "code": code,
+                            "message": message,
+                            // Lets the UI offer `restore_last_good_config`
+                            "known_good_available": matches!(known_good::load(&app_handle), Ok(Some(_))),
+                        }),
+                    );
                 }
-              }
+            });
+
+            eprintln!("Tauri app setup completed successfully (backend starting in background)");
+            Ok(())
+        })
+        .on_window_event(|window, event| {
+            // Stop the backend before the window goes away so it can't be orphaned
+            if let tauri::WindowEvent::CloseRequested { api, .. } = event {
+                info!("Window close requested, stopping backend before closing...");
+                api.prevent_close();
+
+                // Kill off the event loop thread, then close the window once the backend is gone
+                let window = window.clone();
+                std::thread::spawn(move || {
+                    // A suspended backend can't act on the graceful signal and would only time out
+                    suspend::resume_before_stop(window.app_handle());
+                    if let Some(state) = window.app_handle().try_state::<Mutex<Option<Child>>>()
+                        && let Ok(mut process) = state.lock()
+                        && let Some(mut child) = process.take()
+                    {
+                        kill_backend_process(&mut child);
+                        // Bounded so a stuck process can't keep the window open
+                        if !shutdown::wait_for_exit(&mut child, WINDOW_CLOSE_KILL_TIMEOUT) {
+                            warn!("Backend did not exit before the window closed");
+                        }
+                    }
+                    if let Err(e) = window.destroy() {
+                        warn!("Failed to close window: {}", e);
+                    }
+                });
             }
-          }
-        });
-        // Window closes immediately - cleanup happens in background
-      }
-    })
-    .build(tauri::generate_context!())
-    .unwrap_or_else(|e| {
-      eprintln!("Fatal error starting Tauri application: {}", e);
-      std::process::exit(1);
-    })
-    .run(|app, event| {
-      if let tauri::RunEvent::ExitRequested { .. } = event {
-        info!("App exit requested, cleaning up backend process...");
-        // Cleanup backend process synchronously on app exit to ensure it completes
-        if let Some(state) = app.try_state::<Mutex<Option<Child>>>() {
-          if let Ok(mut process) = state.lock() {
-            if let Some(mut child) = process.take() {
-              kill_backend_process(&mut child);
-              // Wait a moment to ensure process is killed
-              std::thread::sleep(std::time::Duration::from_millis(200));
+        })
+        .build(tauri::generate_context!())
+        .unwrap_or_else(|e| {
+            eprintln!("Fatal error starting Tauri application: {}", e);
+            std::process::exit(1);
+        })
+        .run(|app, event| {
+            if let tauri::RunEvent::ExitRequested { .. } = event {
+                info!("App exit requested, cleaning up backend process...");
+                // Cleanup backend process synchronously on app exit to ensure it completes
+                shutdown_backend(app);
             }
-          }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Fresh empty directory under the system temp dir, unique to this test run
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!(
+            "budget-planer-test-{}-{}",
+            std::process::id(),
+            name
+        ));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// A file large enough not to be taken for a placeholder
+    fn write_fake_backend(path: &Path) {
+        std::fs::write(path, vec![0u8; 2048]).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn resource_dir_without_list_permission_still_yields_the_backend() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let resource_dir = temp_dir("unlistable-resources");
+        write_fake_backend(&resource_dir.join(BACKEND_EXE_NAME));
+        // Execute-only: entries can be opened by name, but the directory can't be listed
+        std::fs::set_permissions(&resource_dir, std::fs::Permissions::from_mode(0o111)).unwrap();
+
+        let candidates = diagnostics::probe_candidates(&resource_dir_exe_paths(&resource_dir));
+        let found = candidates
+            .iter()
+            .find(|candidate| usable_backend_exe(candidate, false))
+            .map(|candidate| candidate.path.clone());
+
+        std::fs::set_permissions(&resource_dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let _ = std::fs::remove_dir_all(&resource_dir);
+        assert_eq!(found, Some(resource_dir.join(BACKEND_EXE_NAME)));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn symlinked_backend_dir_is_probed_once() {
+        let root = temp_dir("symlinked-backend");
+        let dist = root.join("project").join("backend").join("dist");
+        std::fs::create_dir_all(&dist).unwrap();
+        write_fake_backend(&dist.join(BACKEND_EXE_NAME));
+        std::os::unix::fs::symlink(root.join("project"), root.join("link")).unwrap();
+
+        let paths = backend_dist_exe_paths(&[root.join("project"), root.join("link")]);
+        let paths = dedup_by_canonical_path(
+            paths
+                .into_iter()
+                .chain([
+                    root.join("project")
+                        .join("backend")
+                        .join("dist")
+                        .join(BACKEND_EXE_NAME),
+                    root.join("link")
+                        .join("backend")
+                        .join("dist")
+                        .join(BACKEND_EXE_NAME),
+                ])
+                .collect(),
+        );
+
+        let _ = std::fs::remove_dir_all(&root);
+        let backend_paths: Vec<_> = paths
+            .iter()
+            .filter(|path| path.file_name() == Some(std::ffi::OsStr::new(BACKEND_EXE_NAME)))
+            .collect();
+        assert_eq!(backend_paths.len(), 1, "{:?}", paths);
+    }
+
+    fn health_response(status: u16, body: &str) -> health_client::HealthResponse {
+        health_client::HealthResponse {
+            status,
+            body: body.to_string(),
         }
-        // Also kill any process on port 8000 as a fallback
-        kill_process_on_port(8000);
-      }
-    });
+    }
+
+    /// Config that expects the `"status": "ok"` field the backend's health view returns
+    fn config_expecting_ok_status() -> config::Config {
+        config::Config {
+            health_body_contains: "\"status\":\"ok\"".to_string(),
+            ..config::Config::default()
+        }
+    }
+
+    #[test]
+    fn healthy_response_is_accepted() {
+        let body = r#"{"status":"ok","message":"Backend is running"}"#;
+        assert!(
+            check_health_response(health_response(200, body), &config::Config::default()).is_ok()
+        );
+        assert!(
+            check_health_response(health_response(200, body), &config_expecting_ok_status())
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn unhealthy_status_is_rejected() {
+        let body = r#"{"status":"ok"}"#;
+        let err = check_health_response(health_response(503, body), &config::Config::default())
+            .unwrap_err();
+        assert!(err.contains("503"), "{}", err);
+
+        let config = config::Config {
+            health_statuses: vec![204],
+            ..config::Config::default()
+        };
+        assert!(check_health_response(health_response(200, body), &config).is_err());
+        assert!(check_health_response(health_response(204, ""), &config).is_ok());
+    }
+
+    #[test]
+    fn non_json_body_only_fails_when_a_body_match_is_configured() {
+        let body = "<html><body>Bad Gateway</body></html>";
+        assert!(
+            check_health_response(health_response(200, body), &config::Config::default()).is_ok()
+        );
+        assert!(
+            check_health_response(health_response(200, body), &config_expecting_ok_status())
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn body_missing_the_expected_field_is_rejected() {
+        let body = r#"{"message":"Backend is running"}"#;
+        let err = check_health_response(health_response(200, body), &config_expecting_ok_status())
+            .unwrap_err();
+        assert!(err.contains("body does not contain"), "{}", err);
+    }
+
+    #[test]
+    fn overlapping_launch_requests_share_one_restart() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::{Arc, mpsc};
+
+        let gate = Arc::new(LaunchGate::default());
+        let restarts = Arc::new(AtomicUsize::new(0));
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+
+        let first = {
+            let gate = Arc::clone(&gate);
+            let restarts = Arc::clone(&restarts);
+            std::thread::spawn(move || {
+                gate.run(
+                    || {
+                        started_tx.send(()).unwrap();
+                        release_rx.recv().unwrap();
+                        Ok(())
+                    },
+                    || {
+                        restarts.fetch_add(1, Ordering::SeqCst);
+                        Ok(())
+                    },
+                )
+            })
+        };
+        started_rx.recv().unwrap();
+
+        // Both arrive while the first sequence is still running
+        for _ in 0..2 {
+            let result = gate.run(
+                || panic!("sequence ran while another was running"),
+                || Ok(()),
+            );
+            assert_eq!(result, Err(RESTART_IN_PROGRESS.to_string()));
+        }
+        release_tx.send(()).unwrap();
+
+        assert_eq!(first.join().unwrap(), Ok(()));
+        assert_eq!(restarts.load(Ordering::SeqCst), 1);
+        // The gate is open again afterwards
+        assert_eq!(gate.run(|| Ok(()), || unreachable!()), Ok(()));
+    }
 }
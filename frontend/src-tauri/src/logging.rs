@@ -0,0 +1,265 @@
+use log::{LevelFilter, info, warn};
+use std::collections::VecDeque;
+use std::hash::{BuildHasher, Hasher, RandomState};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri_plugin_log::{RotationStrategy, Target, TargetKind, TimezoneStrategy};
+
+/// Base name of the app log file in the platform log directory (`app.log`)
+const LOG_FILE_NAME: &str = "app";
+
+/// Size at which the log plugin rotates `app.log` to `app_<date>.log`
+const LOG_MAX_FILE_SIZE: u128 = 2 * 1024 * 1024;
+
+/// Rotated logs kept (gzipped) besides the current `app.log`
+const MAX_ROTATED_LOGS: usize = 10;
+
+/// Set when the log plugin failed to register and logs go to stderr instead
+static FALLBACK_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// Correlation ID of the current backend startup attempt, empty before the first one
+static ATTEMPT_ID: Mutex<String> = Mutex::new(String::new());
+
+/// Start a new startup attempt with a fresh short random correlation ID and return it
+/// Every log line and event from then on carries the ID until the next attempt begins
+pub fn begin_attempt() -> String {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u128(
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map(|duration| duration.as_nanos())
+            .unwrap_or(0),
+    );
+    let id = format!("{:08x}", hasher.finish() as u32);
+    if let Ok(mut current) = ATTEMPT_ID.lock() {
+        *current = id.clone();
+    }
+    id
+}
+
+/// Correlation ID of the current startup attempt, if one has begun
+pub fn attempt_id() -> Option<String> {
+    ATTEMPT_ID
+        .lock()
+        .ok()
+        .map(|id| id.clone())
+        .filter(|id| !id.is_empty())
+}
+
+/// The plugin's default line prefix (timestamp, target, level), plus the startup attempt's
+/// correlation ID
+fn line_prefix(record: &log::Record) -> String {
+    let now = TimezoneStrategy::UseUtc.get_now();
+    let attempt = attempt_id()
+        .map(|id| format!("[{}]", id))
+        .unwrap_or_default();
+    format!(
+        "[{:04}-{:02}-{:02}][{:02}:{:02}:{:02}][{}][{}]{}",
+        now.year(),
+        u8::from(now.month()),
+        now.day(),
+        now.hour(),
+        now.minute(),
+        now.second(),
+        record.target(),
+        record.level(),
+        attempt
+    )
+}
+
+/// Targets logged at the app's level; everything else (hyper, reqwest, rusqlite, tao, ...) is
+/// capped at info so raising the level to debug or trace doesn't flood `app.log`
+const OWN_TARGETS: [&str; 2] = [env!("CARGO_CRATE_NAME"), "webview"];
+
+/// Whether a record passes the per-target cap on dependency logs
+fn target_enabled(metadata: &log::Metadata) -> bool {
+    let own = OWN_TARGETS.iter().any(|own| {
+        metadata
+            .target()
+            .strip_prefix(own)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with("::") || rest.starts_with(':'))
+    });
+    own || metadata.level() <= LevelFilter::Info
+}
+
+/// Minimal logger writing to stderr, used when the log plugin can't be registered
+struct StderrLogger;
+
+impl log::Log for StderrLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level() && target_enabled(metadata)
+    }
+
+    fn log(&self, record: &log::Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("{} {}", line_prefix(record), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// Register the log plugin with every level enabled and gate output with the global max level
+/// `log::set_max_level` acts as the reloadable filter, so the level can change without a restart;
+/// dependencies stay capped at info whatever the level
+/// Logs go to stdout and to `app.log` in the platform log directory (or the portable data
+/// directory's `logs`); if the plugin fails (e.g. the log file is locked), they go to stderr
+/// instead of being lost
+pub fn init(app: &tauri::App) {
+    let file_target = match crate::storage::portable_dir() {
+        Some(dir) => TargetKind::Folder {
+            path: dir.join("logs"),
+            file_name: Some(LOG_FILE_NAME.to_string()),
+        },
+        None => TargetKind::LogDir {
+            file_name: Some(LOG_FILE_NAME.to_string()),
+        },
+    };
+    let registered = app.handle().plugin(
+        tauri_plugin_log::Builder::default()
+            .targets([Target::new(TargetKind::Stdout), Target::new(file_target)])
+            .level(LevelFilter::Trace)
+            .filter(target_enabled)
+            .rotation_strategy(RotationStrategy::KeepAll)
+            .max_file_size(LOG_MAX_FILE_SIZE)
+            .format(|out, message, record| {
+                out.finish(format_args!("{} {}", line_prefix(record), message))
+            })
+            .build(),
+    );
+    log::set_max_level(LevelFilter::Info);
+    if let Err(e) = registered {
+        if log::set_boxed_logger(Box::new(StderrLogger)).is_ok() {
+            FALLBACK_ACTIVE.store(true, Ordering::SeqCst);
+            warn!(
+                "Log plugin failed to initialize ({}), logging to stderr only",
+                e
+            );
+        } else {
+            eprintln!("Log plugin failed to initialize: {}", e);
+        }
+    }
+}
+
+/// Log every panic to the app log before the default hook runs
+/// Release builds abort on panic (`panic = "abort"`), so for them this entry is the only report
+pub fn install_panic_hook() {
+    let default_hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(move |info| {
+        log::error!("{}", info);
+        log::logger().flush();
+        default_hook(info);
+    }));
+}
+
+/// Whether logs go to the stderr fallback because the log plugin failed
+pub fn fallback_active() -> bool {
+    FALLBACK_ACTIVE.load(Ordering::SeqCst)
+}
+
+/// Location of the app log file written by the log plugin
+pub fn app_log_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    crate::storage::log_dir(app).map(|dir| dir.join(format!("{}.log", LOG_FILE_NAME)))
+}
+
+/// Rotated logs (`app_<date>.log`, gzipped or not), oldest first; the date sorts by name
+fn rotated_logs(dir: &Path) -> Vec<PathBuf> {
+    let prefix = format!("{}_", LOG_FILE_NAME);
+    let mut logs: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .flatten()
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| {
+                            name.starts_with(&prefix)
+                                && (name.ends_with(".log") || name.ends_with(".log.gz"))
+                        })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    logs.sort();
+    logs
+}
+
+/// Gzip logs the plugin rotated away and delete all but the newest `MAX_ROTATED_LOGS`
+pub fn compress_rotated_logs(app: &tauri::AppHandle) {
+    let Ok(dir) = crate::storage::log_dir(app) else {
+        return;
+    };
+    for log in rotated_logs(&dir) {
+        if !crate::backups::is_compressed(&log)
+            && let Err(e) = crate::backups::compress_file(&log)
+        {
+            warn!("Failed to compress rotated log {:?}: {}", log, e);
+        }
+    }
+    let logs = rotated_logs(&dir);
+    for log in logs
+        .iter()
+        .take(logs.len().saturating_sub(MAX_ROTATED_LOGS))
+    {
+        if let Err(e) = std::fs::remove_file(log) {
+            warn!("Failed to delete old log {:?}: {}", log, e);
+        }
+    }
+}
+
+/// Last `lines` lines of the app log, continuing into the rotated (possibly gzipped) logs when
+/// the current file is shorter
+pub fn tail_app_log(app: &tauri::AppHandle, lines: usize) -> Result<Vec<String>, String> {
+    let path = app_log_path(app)?;
+    let mut files = vec![path.clone()];
+    if let Some(dir) = path.parent() {
+        files.extend(rotated_logs(dir).into_iter().rev());
+    }
+
+    let mut tail: VecDeque<String> = VecDeque::new();
+    for (index, file) in files.iter().enumerate() {
+        if tail.len() >= lines {
+            break;
+        }
+        let contents = match crate::backups::read_decompressed(file) {
+            Ok(contents) => contents,
+            Err(e) if index == 0 => return Err(format!("Could not read {:?}: {}", file, e)),
+            Err(e) => {
+                warn!("Could not read rotated log {:?}: {}", file, e);
+                break;
+            }
+        };
+        let contents = String::from_utf8_lossy(&contents);
+        for line in contents.lines().rev() {
+            if tail.len() >= lines {
+                break;
+            }
+            tail.push_front(line.to_string());
+        }
+    }
+    Ok(tail.into())
+}
+
+/// Parse a level name such as "debug" or "INFO"
+pub fn parse_level(level: &str) -> Result<LevelFilter, String> {
+    level
+        .trim()
+        .parse::<LevelFilter>()
+        .map_err(|_| format!("Unknown log level '{}'", level))
+}
+
+/// Change the active log level
+pub fn set_level(level: LevelFilter) {
+    log::set_max_level(level);
+    info!("Log level set to {}", level);
+}
+
+/// Apply the configured level, keeping the current one if the value is invalid
+pub fn apply_config_level(level: &str) {
+    match parse_level(level) {
+        Ok(level) => set_level(level),
+        Err(e) => warn!("{}, keeping log level {}", e, log::max_level()),
+    }
+}
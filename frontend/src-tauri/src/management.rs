@@ -0,0 +1,219 @@
+use crate::diagnostics::{self, BackendMode};
+use log::{info, warn};
+use std::process::{Command, Output};
+
+/// Management commands that may be run from the UI; everything else is rejected
+pub const ALLOWED_COMMANDS: &[&str] = &["showmigrations", "dumpdata", "check", "clearsessions"];
+
+/// Options each allowlisted command accepts from the UI; options taking a value must be given as
+/// `--option=value`. Anything else starting with `-` is rejected, so options such as
+/// `--settings`, `--pythonpath` (import arbitrary modules) or `--output` (write arbitrary files)
+/// can't be smuggled in
+fn allowed_options(name: &str) -> &'static [&'static str] {
+    match name {
+        "showmigrations" => &["--list", "-l", "--plan", "-p"],
+        "dumpdata" => &[
+            "--indent=",
+            "--format=",
+            "--natural-foreign",
+            "--natural-primary",
+        ],
+        "check" => &["--deploy", "--tag=", "--fail-level="],
+        _ => &[],
+    }
+}
+
+/// Whether `arg` is an app label or `app_label.ModelName`, the only positional arguments allowed
+fn is_label(arg: &str) -> bool {
+    let part = |part: &str| {
+        part.chars()
+            .next()
+            .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+            && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+    };
+    let mut parts = arg.split('.');
+    let valid = parts.next().is_some_and(part) && parts.next().is_none_or(part);
+    valid && parts.next().is_none()
+}
+
+/// Check the arguments of an allowlisted command against its options and label rules
+fn validate_args(name: &str, args: &[String]) -> Result<(), String> {
+    let options = allowed_options(name);
+    for arg in args {
+        let allowed = if arg.starts_with('-') {
+            options.iter().any(|option| match option.strip_suffix('=') {
+                Some(prefix) => arg
+                    .strip_prefix(prefix)
+                    .and_then(|rest| rest.strip_prefix('='))
+                    .is_some_and(|value| !value.is_empty()),
+                None => arg == option,
+            })
+        } else {
+            name != "clearsessions" && is_label(arg)
+        };
+        if !allowed {
+            return Err(format!("Argument '{}' is not allowed for {}", arg, name));
+        }
+    }
+    Ok(())
+}
+
+/// Build a command running a Django management subcommand the same way the backend was launched:
+/// through the bundled executable's `--manage` flag or through `manage.py` in the Python backend
+pub fn command(app: &tauri::AppHandle, args: &[&str]) -> Result<Command, String> {
+    let db_path = crate::active_db_path(app).ok_or("Active database path is not known")?;
+    let cmd = match diagnostics::startup_info(app).backend_mode {
+        Some(BackendMode::Bundled { executable }) => {
+            let mut cmd = Command::new(executable);
+            cmd.arg("--database-path");
+            cmd.arg(db_path.to_string_lossy().to_string());
+            cmd.arg("--manage");
+            cmd.args(args);
+            cmd
+        }
+        Some(BackendMode::Python {
+            interpreter,
+            backend_dir,
+        }) => {
+            let mut cmd = Command::new(interpreter);
+            cmd.current_dir(backend_dir);
+            cmd.arg("manage.py");
+            cmd.args(args);
+            cmd.env("DATABASE_PATH", db_path.to_string_lossy().to_string());
+            cmd.env("DJANGO_SETTINGS_MODULE", "config.settings");
+            cmd
+        }
+        None => return Err("The backend has not been started yet".to_string()),
+    };
+
+    let mut cmd = cmd;
+    crate::process::configure_command(&mut cmd);
+    crate::process::use_c_locale(&mut cmd);
+    // Same environment as the server, so a database opened read-only stays read-only here too
+    crate::apply_backend_env(app, &mut cmd);
+
+    Ok(cmd)
+}
+
+/// Run a management subcommand to completion and capture its output
+pub fn run(app: &tauri::AppHandle, args: &[&str]) -> Result<Output, String> {
+    command(app, args)?
+        .output()
+        .map_err(|e| format!("Failed to run management command {:?}: {}", args, e))
+}
+
+/// Run an allowlisted management command and return its output
+pub fn run_allowed(app: &tauri::AppHandle, name: &str, args: &[String]) -> Result<String, String> {
+    if !ALLOWED_COMMANDS.contains(&name) {
+        return Err(format!(
+            "Management command '{}' is not allowed (allowed: {})",
+            name,
+            ALLOWED_COMMANDS.join(", ")
+        ));
+    }
+    validate_args(name, args)?;
+
+    let mut full_args = vec![name];
+    full_args.extend(args.iter().map(String::as_str));
+    let output = run(app, &full_args)?;
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    } else {
+        Err(format!(
+            "{} failed: {}",
+            name,
+            String::from_utf8_lossy(&output.stderr).trim()
+        ))
+    }
+}
+
+/// Messages from `check` output, e.g. "?: (security.W004) You have not set ..." with any
+/// following "HINT:" line appended
+pub fn parse_check_output(output: &str) -> Vec<String> {
+    let mut messages: Vec<String> = Vec::new();
+    for line in output.lines().map(str::trim) {
+        if let Some(hint) = line.strip_prefix("HINT:") {
+            if let Some(last) = messages.last_mut() {
+                last.push_str(" (hint: ");
+                last.push_str(hint.trim());
+                last.push(')');
+            }
+        } else if line.contains(": (") && line.contains(')') {
+            messages.push(line.to_string());
+        }
+    }
+    messages
+}
+
+/// Run Django's `check` with the backend's environment and return the issues it reports
+/// Errors make `check` fail; its messages are returned as the error so none are lost
+pub fn check(app: &tauri::AppHandle) -> Result<Vec<String>, String> {
+    let output = command(app, &["check"])?
+        .output()
+        .map_err(|e| format!("Failed to run check: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let messages = parse_check_output(&format!("{}\n{}", stdout, stderr));
+    if output.status.success() {
+        return Ok(messages);
+    }
+    if messages.is_empty() {
+        Err(format!("check failed: {}", stderr.trim()))
+    } else {
+        Err(messages.join("\n"))
+    }
+}
+
+/// Run `check` before the server is spawned, failing startup on configuration errors
+/// Warnings are only logged
+pub fn preflight(app: &tauri::AppHandle) -> Result<(), String> {
+    info!("Running pre-flight configuration check...");
+    let warnings = check(app).map_err(|e| format!("Backend configuration check failed: {}", e))?;
+    for warning in &warnings {
+        warn!("Backend check: {}", warning);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn options_loading_code_or_writing_files_are_rejected() {
+        for arg in [
+            "--settings=evil",
+            "--settings",
+            "--pythonpath=/tmp",
+            "-o",
+            "--output=/etc/passwd",
+            "--database=other",
+            "--traceback",
+        ] {
+            assert!(
+                validate_args("dumpdata", &args(&[arg])).is_err(),
+                "{} was accepted",
+                arg
+            );
+        }
+    }
+
+    #[test]
+    fn labels_and_listed_options_are_accepted() {
+        assert!(validate_args("dumpdata", &args(&["budgets.Budget", "--indent=2"])).is_ok());
+        assert!(validate_args("showmigrations", &args(&["budgets", "--plan"])).is_ok());
+        assert!(validate_args("check", &args(&["--deploy"])).is_ok());
+    }
+
+    #[test]
+    fn malformed_labels_and_extra_arguments_are_rejected() {
+        assert!(validate_args("dumpdata", &args(&["../db.sqlite3"])).is_err());
+        assert!(validate_args("dumpdata", &args(&["a.b.c"])).is_err());
+        assert!(validate_args("dumpdata", &args(&["--indent="])).is_err());
+        assert!(validate_args("clearsessions", &args(&["budgets"])).is_err());
+    }
+}
@@ -0,0 +1,108 @@
+use log::{info, warn};
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use tauri::Manager;
+
+/// File name packagers use to declare the backend executable
+pub const MANIFEST_FILE: &str = "backend.manifest.json";
+
+/// Packager-provided description of the backend executable, used instead of heuristic discovery
+#[derive(Debug, Clone, Deserialize)]
+pub struct BackendManifest {
+    /// Backend executable; relative paths are resolved against the manifest's directory
+    pub executable: PathBuf,
+    /// Expected SHA-256 of the executable as hex; not checked when absent
+    #[serde(default)]
+    pub sha256: Option<String>,
+    /// Backend version, for logs
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Manifest file this was read from
+    #[serde(skip)]
+    pub source: PathBuf,
+}
+
+impl BackendManifest {
+    /// Whether `path` has the declared hash (always true when no hash is declared)
+    pub fn matches(&self, path: &Path) -> bool {
+        let Some(expected) = &self.sha256 else {
+            return true;
+        };
+        sha256_file(path).is_ok_and(|actual| actual.eq_ignore_ascii_case(expected.trim()))
+    }
+}
+
+/// Locations checked for the manifest: next to the app executable, then the bundled resources
+fn manifest_locations(app: &tauri::AppHandle) -> Vec<PathBuf> {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(Path::to_path_buf));
+    exe_dir
+        .into_iter()
+        .chain(app.path().resource_dir().ok())
+        .map(|dir| dir.join(MANIFEST_FILE))
+        .collect()
+}
+
+/// Read and verify the backend manifest
+/// `Ok(None)` when there is none; a manifest that is present but unreadable, names a missing
+/// executable or doesn't match its hash is an error rather than falling back to discovery
+pub fn load(app: &tauri::AppHandle) -> Result<Option<BackendManifest>, String> {
+    let Some(source) = manifest_locations(app)
+        .into_iter()
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    info!("Found backend manifest: {:?}", source);
+
+    let contents = std::fs::read_to_string(&source)
+        .map_err(|e| format!("Failed to read {:?}: {}", source, e))?;
+    let mut manifest: BackendManifest = serde_json::from_str(&contents)
+        .map_err(|e| format!("Invalid backend manifest {:?}: {}", source, e))?;
+    if manifest.executable.is_relative()
+        && let Some(dir) = source.parent()
+    {
+        manifest.executable = dir.join(&manifest.executable);
+    }
+    manifest.source = source;
+
+    if !manifest.executable.is_file() {
+        return Err(format!(
+            "Backend executable {:?} declared in {:?} does not exist",
+            manifest.executable, manifest.source
+        ));
+    }
+    match &manifest.sha256 {
+        Some(_) if !manifest.matches(&manifest.executable) => {
+            return Err(format!(
+                "Backend executable {:?} does not match the SHA-256 declared in {:?}",
+                manifest.executable, manifest.source
+            ));
+        }
+        Some(_) => info!("Backend executable hash matches the manifest"),
+        None => warn!("Backend manifest declares no sha256, the executable is not verified"),
+    }
+    Ok(Some(manifest))
+}
+
+/// SHA-256 of a file as lowercase hex
+pub fn sha256_file(path: &Path) -> std::io::Result<String> {
+    let mut file = std::fs::File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 64 * 1024];
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{:02x}", byte))
+        .collect())
+}
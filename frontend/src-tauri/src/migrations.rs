@@ -0,0 +1,226 @@
+use log::{info, warn};
+use serde::Serialize;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Health deadline while a migration is still running (large schema changes can be slow)
+const MIGRATING_HEALTH_TIMEOUT: Duration = Duration::from_secs(120);
+/// Grace period granted after migrations have finished
+const POST_MIGRATION_HEALTH_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// Progress of the background migration thread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MigrationPhase {
+    NotStarted,
+    Running,
+    Finished(Instant),
+}
+
+/// Migration phase shared between the migration thread and the health poll
+#[derive(Clone)]
+pub struct MigrationTracker(Arc<Mutex<MigrationPhase>>);
+
+impl Default for MigrationTracker {
+    fn default() -> Self {
+        Self(Arc::new(Mutex::new(MigrationPhase::NotStarted)))
+    }
+}
+
+impl MigrationTracker {
+    pub fn set(&self, phase: MigrationPhase) {
+        if let Ok(mut current) = self.0.lock() {
+            *current = phase;
+        }
+    }
+
+    pub fn phase(&self) -> MigrationPhase {
+        self.0
+            .lock()
+            .map(|p| *p)
+            .unwrap_or(MigrationPhase::NotStarted)
+    }
+
+    /// Deadline for the health poll that started at `start`, adapted to migration progress
+    /// `timeout` applies while no migration activity has been observed
+    pub fn health_deadline(&self, start: Instant, timeout: Duration) -> Instant {
+        match self.phase() {
+            MigrationPhase::NotStarted => start + timeout,
+            MigrationPhase::Running => start + MIGRATING_HEALTH_TIMEOUT.max(timeout),
+            MigrationPhase::Finished(at) => at.max(start) + POST_MIGRATION_HEALTH_TIMEOUT,
+        }
+    }
+}
+
+/// Whether `showmigrations --plan` output lists any unapplied migration (`[ ]` marker)
+pub fn plan_has_pending(plan_output: &str) -> bool {
+    plan_output
+        .lines()
+        .any(|line| line.trim_start().starts_with("[ ]"))
+}
+
+/// Migrations applied by one `migrate` run, sent to the frontend as `backend://migrations_done`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct MigrationReport {
+    /// Applied migrations as `app.name`, in order
+    pub applied: Vec<String>,
+    pub had_changes: bool,
+}
+
+impl MigrationReport {
+    /// Collect the `Applying app.0001_name... OK` lines of `migrate` output
+    pub fn from_output(migrate_output: &str) -> Self {
+        let applied: Vec<String> = migrate_output
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("Applying "))
+            .map(|rest| rest.split("...").next().unwrap_or(rest).trim().to_string())
+            .collect();
+        Self {
+            had_changes: !applied.is_empty(),
+            applied,
+        }
+    }
+}
+
+/// Tell the frontend which migrations a successful `migrate` run applied
+pub fn notify_done(app: &tauri::AppHandle, migrate_output: &str) {
+    let report = MigrationReport::from_output(migrate_output);
+    if report.had_changes {
+        info!("Applied {} migration(s)", report.applied.len());
+    }
+    crate::events::emit(app, "backend://migrations_done", report);
+}
+
+/// Check the active database for unapplied migrations without touching it
+pub fn pending(app: &tauri::AppHandle) -> Result<bool, String> {
+    let output = crate::management::run(app, &["showmigrations", "--plan"])?;
+    if !output.status.success() {
+        return Err(format!(
+            "showmigrations failed: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        ));
+    }
+    Ok(plan_has_pending(&String::from_utf8_lossy(&output.stdout)))
+}
+
+/// Emit `backend://migrations_pending` when the database needs updating, for `migrate_confirm` mode
+pub fn notify_if_pending(app: &tauri::AppHandle) {
+    match pending(app) {
+        Ok(true) => {
+            info!("Database migrations are pending, waiting for user confirmation");
+            crate::events::emit(app, "backend://migrations_pending", ());
+        }
+        Ok(false) => info!("No pending database migrations"),
+        Err(e) => warn!("Could not check for pending migrations: {}", e),
+    }
+}
+
+/// Apply all pending migrations to the active database and return Django's output
+pub fn apply(app: &tauri::AppHandle) -> Result<String, String> {
+    if crate::config::current(app).read_only {
+        return Err("The database is open read-only, migrations are disabled".to_string());
+    }
+    info!("Applying database migrations...");
+    let output = crate::management::run(app, &["migrate", "--noinput"])?;
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if output.status.success() {
+        info!("Database migrations completed successfully");
+        notify_done(app, &stdout);
+        Ok(stdout)
+    } else {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        Err(format!("Migration failed: {}", stderr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MIGRATE_APPLIED: &str = "\
+Operations to perform:
+  Apply all migrations: admin, auth, budget, contenttypes, sessions
+Running migrations:
+  Applying budget.0004_category_color... OK
+  Applying budget.0005_transaction_note... OK (0.012s)
+";
+
+    const MIGRATE_NOTHING: &str = "\
+Operations to perform:
+  Apply all migrations: admin, auth, budget, contenttypes, sessions
+Running migrations:
+  No migrations to apply.
+";
+
+    const PLAN_PENDING: &str = "\
+[X]  contenttypes.0001_initial
+[X]  budget.0004_category_color
+[ ]  budget.0005_transaction_note
+";
+
+    const PLAN_APPLIED: &str = "\
+[X]  contenttypes.0001_initial
+[X]  budget.0004_category_color
+[X]  budget.0005_transaction_note
+";
+
+    #[test]
+    fn health_deadline_follows_migration_progress() {
+        let tracker = MigrationTracker::default();
+        let start = Instant::now();
+        let timeout = Duration::from_secs(30);
+
+        assert_eq!(tracker.health_deadline(start, timeout), start + timeout);
+
+        tracker.set(MigrationPhase::Running);
+        assert_eq!(
+            tracker.health_deadline(start, timeout),
+            start + MIGRATING_HEALTH_TIMEOUT
+        );
+        // A configured timeout longer than the migration allowance is kept
+        let long_timeout = MIGRATING_HEALTH_TIMEOUT + Duration::from_secs(60);
+        assert_eq!(
+            tracker.health_deadline(start, long_timeout),
+            start + long_timeout
+        );
+
+        let finished = start + Duration::from_secs(50);
+        tracker.set(MigrationPhase::Finished(finished));
+        assert_eq!(
+            tracker.health_deadline(start, timeout),
+            finished + POST_MIGRATION_HEALTH_TIMEOUT
+        );
+        // Finishing before the poll started counts from the poll's start
+        let poll_start = finished + Duration::from_secs(5);
+        assert_eq!(
+            tracker.health_deadline(poll_start, timeout),
+            poll_start + POST_MIGRATION_HEALTH_TIMEOUT
+        );
+    }
+
+    #[test]
+    fn report_lists_applied_migrations_in_order() {
+        let report = MigrationReport::from_output(MIGRATE_APPLIED);
+        assert!(report.had_changes);
+        assert_eq!(
+            report.applied,
+            vec![
+                "budget.0004_category_color".to_string(),
+                "budget.0005_transaction_note".to_string(),
+            ]
+        );
+    }
+
+    #[test]
+    fn report_is_empty_when_there_was_nothing_to_apply() {
+        let report = MigrationReport::from_output(MIGRATE_NOTHING);
+        assert!(!report.had_changes);
+        assert!(report.applied.is_empty());
+    }
+
+    #[test]
+    fn plan_detects_unapplied_migrations() {
+        assert!(plan_has_pending(PLAN_PENDING));
+        assert!(!plan_has_pending(PLAN_APPLIED));
+        assert!(!plan_has_pending(""));
+    }
+}
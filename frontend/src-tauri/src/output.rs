@@ -0,0 +1,115 @@
+use log::{debug, warn};
+use std::collections::VecDeque;
+use std::io::{BufRead, BufReader, Read};
+use std::process::Child;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+/// Stderr lines kept for error reports
+const TAIL_LINES: usize = 20;
+
+/// Most recent stderr lines of a backend, for explaining why it exited
+#[derive(Clone, Default)]
+pub struct StderrTail {
+    lines: Arc<Mutex<VecDeque<String>>>,
+    closed: Arc<AtomicBool>,
+}
+
+impl StderrTail {
+    fn push(&self, line: &str) {
+        if let Ok(mut lines) = self.lines.lock() {
+            if lines.len() == TAIL_LINES {
+                lines.pop_front();
+            }
+            lines.push_back(line.to_string());
+        }
+    }
+
+    /// Buffered lines, after waiting up to `timeout` for the reader to reach the end of the pipe
+    pub fn collect(&self, timeout: Duration) -> String {
+        let deadline = Instant::now() + timeout;
+        while !self.closed.load(Ordering::SeqCst) && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        self.lines
+            .lock()
+            .map(|lines| lines.iter().cloned().collect::<Vec<_>>().join("\n"))
+            .unwrap_or_default()
+    }
+}
+
+/// Read the backend's piped stdout and stderr line by line on background threads
+/// Lines are logged, and with `dev_console` also emitted as `backend://stdout` / `backend://stderr`
+/// The returned tail holds the last stderr lines (empty when stderr isn't piped)
+pub fn forward(app: &tauri::AppHandle, child: &mut Child) -> StderrTail {
+    let dev_console = crate::config::current(app).dev_console;
+    let tail = StderrTail::default();
+    if let Some(stdout) = child.stdout.take() {
+        spawn_reader(app.clone(), stdout, "backend://stdout", dev_console, None);
+    }
+    match child.stderr.take() {
+        Some(stderr) => spawn_reader(
+            app.clone(),
+            stderr,
+            "backend://stderr",
+            dev_console,
+            Some(tail.clone()),
+        ),
+        None => tail.closed.store(true, Ordering::SeqCst),
+    }
+    tail
+}
+
+fn spawn_reader(
+    app: tauri::AppHandle,
+    stream: impl Read + Send + 'static,
+    event: &'static str,
+    dev_console: bool,
+    tail: Option<StderrTail>,
+) {
+    std::thread::spawn(move || {
+        let mut reader = BufReader::new(stream);
+        let mut buf = Vec::new();
+        loop {
+            // Read raw bytes: output that isn't UTF-8 (Windows code pages, binary in a traceback)
+            // must not stop the pipe from being drained, or the backend blocks on a full pipe
+            buf.clear();
+            match reader.read_until(b'\n', &mut buf) {
+                Ok(0) => break,
+                Ok(_) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => continue,
+                Err(e) => {
+                    warn!("Error reading backend output: {}", e);
+                    break;
+                }
+            }
+            let line = String::from_utf8_lossy(&buf)
+                .trim_end_matches(['\r', '\n'])
+                .to_string();
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+            // Django logs HTTP requests to stderr - these are informational, not errors
+            // Only log actual errors (containing "Error", "Exception", "Traceback")
+            if trimmed.contains("Error")
+                || trimmed.contains("Exception")
+                || trimmed.contains("Traceback")
+            {
+                warn!("Backend: {}", trimmed);
+            } else {
+                debug!("Backend: {}", trimmed);
+            }
+            if let Some(tail) = &tail {
+                tail.push(trimmed);
+            }
+            if dev_console {
+                crate::events::emit_progress(&app, event, line);
+            }
+        }
+        if let Some(tail) = &tail {
+            tail.closed.store(true, Ordering::SeqCst);
+        }
+    });
+}
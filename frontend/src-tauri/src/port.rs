@@ -0,0 +1,303 @@
+use crate::error::BackendError;
+use log::{debug, info, warn};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, TcpListener};
+use std::process::Command;
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+use tauri::Manager;
+
+/// How long to wait for a port to be released after cleanup before giving up
+pub const PORT_RELEASE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Port the backend listens on unless a conflict forces another one
+pub const DEFAULT_BACKEND_PORT: u16 = 8000;
+
+/// Lowest port the backend may be configured to use; lower ones need elevated privileges
+pub const MIN_BACKEND_PORT: u16 = 1024;
+
+/// Port the backend is currently started on, kept in app state
+pub struct BackendPort(pub Mutex<u16>);
+
+/// Port from app state, or the default if it has not been initialized
+pub fn current_port(app: &tauri::AppHandle) -> u16 {
+    app.try_state::<BackendPort>()
+        .and_then(|state| state.0.lock().ok().map(|port| *port))
+        .unwrap_or(DEFAULT_BACKEND_PORT)
+}
+
+pub fn set_current_port(app: &tauri::AppHandle, port: u16) {
+    if let Some(state) = app.try_state::<BackendPort>()
+        && let Ok(mut current) = state.0.lock()
+    {
+        *current = port;
+    }
+}
+
+/// A process listening on the backend port
+#[derive(Debug, Clone, Serialize)]
+pub struct PortOwner {
+    pub pid: u32,
+    pub name: String,
+    /// Whether the process looks like one of our backend servers
+    pub is_backend: bool,
+}
+
+/// What the user chose to do about a port held by an unrelated process
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PortConflictAction {
+    Kill,
+    UseOtherPort,
+}
+
+/// List the PIDs of processes listening on the given port
+fn find_pids_on_port(port: u16) -> Vec<u32> {
+    let mut pids = Vec::new();
+
+    #[cfg(not(windows))]
+    {
+        // On Linux/macOS, use lsof to find processes on the port
+        let output = crate::process::configure_command(&mut Command::new("lsof"))
+            .args(["-ti", &format!(":{}", port)])
+            .output();
+
+        if let Ok(output) = output {
+            let stdout = String::from_utf8_lossy(&output.stdout);
+            pids.extend(
+                stdout
+                    .lines()
+                    .filter_map(|pid| pid.trim().parse::<u32>().ok()),
+            );
+        }
+    }
+
+    #[cfg(windows)]
+    {
+        // On Windows, use netstat to find processes on the port
+        let output = crate::process::configure_command(&mut Command::new("netstat"))
+            .args(["-ano"])
+            .output();
+
+        if let Ok(output) = output {
+            let output_str = String::from_utf8_lossy(&output.stdout);
+            let port_str = format!(":{}", port);
+            for line in output_str.lines() {
+                if line.contains(&port_str) && line.contains("LISTENING") {
+                    // Extract PID from the last column
+                    if let Some(pid) = line
+                        .split_whitespace()
+                        .last()
+                        .and_then(|pid| pid.parse::<u32>().ok())
+                    {
+                        pids.push(pid);
+                    }
+                }
+            }
+        }
+    }
+
+    pids.sort_unstable();
+    pids.dedup();
+    pids
+}
+
+/// Identify the processes listening on the given port by name and PID
+pub fn identify_port_owners(port: u16) -> Vec<PortOwner> {
+    let pids = find_pids_on_port(port);
+    if pids.is_empty() {
+        return Vec::new();
+    }
+
+    let sys_pids: Vec<Pid> = pids.iter().map(|pid| Pid::from_u32(*pid)).collect();
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&sys_pids),
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::OnlyIfNotSet)
+            .with_exe(UpdateKind::OnlyIfNotSet),
+    );
+
+    pids.into_iter()
+        .map(|pid| match system.process(Pid::from_u32(pid)) {
+            Some(process) => {
+                let name = process.name().to_string_lossy().to_string();
+                PortOwner {
+                    pid,
+                    name,
+                    is_backend: is_backend_process(process),
+                }
+            }
+            None => PortOwner {
+                pid,
+                name: "unknown".to_string(),
+                is_backend: false,
+            },
+        })
+        .collect()
+}
+
+/// Whether a process looks like our backend: the bundled executable or `manage.py runserver`
+fn is_backend_process(process: &sysinfo::Process) -> bool {
+    let cmdline: Vec<String> = process
+        .cmd()
+        .iter()
+        .map(|arg| arg.to_string_lossy().to_string())
+        .collect();
+    process
+        .name()
+        .to_string_lossy()
+        .starts_with("backend-server")
+        || (cmdline.iter().any(|arg| arg.ends_with("manage.py"))
+            && cmdline.iter().any(|arg| arg == "runserver"))
+}
+
+/// Forcefully terminate a single process by PID
+pub fn kill_pid(pid: u32) {
+    #[cfg(not(windows))]
+    {
+        let _ = crate::process::configure_command(&mut Command::new("kill"))
+            .args(["-9", &pid.to_string()])
+            .output();
+    }
+
+    #[cfg(windows)]
+    {
+        let _ = crate::process::configure_command(&mut Command::new("taskkill"))
+            .args(["/F", "/PID", &pid.to_string()])
+            .output();
+    }
+}
+
+/// Kill orphaned backend processes on the specified port and report whether the port ended up free
+/// Processes that are not our backend are left alone and reported as `BackendError::PortConflict`
+/// so the caller can ask the user
+pub fn kill_process_on_port(port: u16) -> Result<bool, BackendError> {
+    info!("Checking for existing processes on port {}", port);
+
+    let mut foreign = None;
+    let mut killed_any = false;
+    for owner in identify_port_owners(port) {
+        if owner.is_backend {
+            info!(
+                "Killing existing backend process {} ({}) on port {}",
+                owner.pid, owner.name, port
+            );
+            kill_pid(owner.pid);
+            killed_any = true;
+        } else {
+            warn!(
+                "Port {} is held by unrelated process {} (PID {}), not killing it",
+                port, owner.name, owner.pid
+            );
+            foreign.get_or_insert(owner);
+        }
+    }
+
+    if killed_any {
+        // Give processes a moment to terminate
+        std::thread::sleep(Duration::from_millis(500));
+    }
+    if let Some(owner) = foreign {
+        return Err(BackendError::PortConflict { port, owner });
+    }
+
+    let free = is_port_free(port);
+    if free {
+        debug!("Port cleanup completed, port {} is free", port);
+    } else {
+        warn!("Port {} is still in use after cleanup", port);
+    }
+    Ok(free)
+}
+
+/// Whether the port can currently be bound on loopback
+/// Uses IPv6 loopback on systems without IPv4 loopback. A listener on `0.0.0.0` or on the other
+/// loopback also counts as taken, since not every platform reports it when binding loopback;
+/// addresses this system can't bind at all are skipped
+pub fn is_port_free(port: u16) -> bool {
+    if TcpListener::bind((default_loopback(), port)).is_err() {
+        return false;
+    }
+    let other_loopback = match default_loopback() {
+        IpAddr::V4(_) => IpAddr::V6(Ipv6Addr::LOCALHOST),
+        IpAddr::V6(_) => IpAddr::V4(Ipv4Addr::LOCALHOST),
+    };
+    [IpAddr::V4(Ipv4Addr::UNSPECIFIED), other_loopback]
+        .into_iter()
+        .all(|addr| match TcpListener::bind((addr, port)) {
+            Ok(_) => true,
+            Err(e) => e.kind() != std::io::ErrorKind::AddrInUse,
+        })
+}
+
+/// Loopback address for the backend: `127.0.0.1`, or `::1` where IPv4 loopback is unavailable
+/// Detected once by binding an ephemeral port
+pub fn default_loopback() -> IpAddr {
+    static LOOPBACK: OnceLock<IpAddr> = OnceLock::new();
+    *LOOPBACK.get_or_init(|| {
+        if TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).is_ok()
+            || TcpListener::bind((Ipv6Addr::LOCALHOST, 0)).is_err()
+        {
+            IpAddr::V4(Ipv4Addr::LOCALHOST)
+        } else {
+            info!("IPv4 loopback is unavailable, using IPv6 loopback (::1)");
+            IpAddr::V6(Ipv6Addr::LOCALHOST)
+        }
+    })
+}
+
+/// Poll until the port can be bound, giving up after `timeout`
+pub fn wait_until_free(port: u16, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        if is_port_free(port) {
+            return true;
+        }
+        if Instant::now() >= deadline {
+            warn!("Port {} was not released within {:?}", port, timeout);
+            return false;
+        }
+        std::thread::sleep(Duration::from_millis(100));
+    }
+}
+
+/// Switch the backend to the first free port after `port`, store it and tell the frontend
+pub fn move_to_next_free_port(app: &tauri::AppHandle, port: u16) -> Result<u16, String> {
+    let new_port = port
+        .checked_add(1)
+        .and_then(find_available_port)
+        .ok_or("No free port available for the backend")?;
+    info!("Moving backend from port {} to {}", port, new_port);
+    set_current_port(app, new_port);
+    crate::events::emit(app, "backend://port_changed", new_port);
+    Ok(new_port)
+}
+
+/// Find the first port at or above `start` that can be bound on loopback
+pub fn find_available_port(start: u16) -> Option<u16> {
+    (start..=u16::MAX).find(|port| is_port_free(*port))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn port_held_on_loopback_is_not_free() {
+        let listener = TcpListener::bind((default_loopback(), 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(!is_port_free(port));
+        drop(listener);
+        assert!(wait_until_free(port, Duration::from_secs(2)));
+    }
+
+    #[test]
+    fn port_held_on_all_interfaces_is_not_free() {
+        let listener = TcpListener::bind((Ipv4Addr::UNSPECIFIED, 0)).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        assert!(!is_port_free(port));
+    }
+}
@@ -0,0 +1,89 @@
+use std::process::Command;
+
+#[cfg(windows)]
+use std::os::windows::process::CommandExt;
+
+/// Windows process creation flag that keeps console programs from opening a console window
+#[cfg(windows)]
+const CREATE_NO_WINDOW: u32 = 0x08000000;
+
+/// Setup shared by every child process: on Windows, never flash a console window
+/// Returns the command so it can be used in a builder chain
+pub fn configure_command(cmd: &mut Command) -> &mut Command {
+    #[cfg(windows)]
+    cmd.creation_flags(CREATE_NO_WINDOW);
+    cmd
+}
+
+/// Run with the C locale, for commands whose output is parsed (e.g. `migrate`'s "Applying" lines)
+/// so it doesn't depend on the user's language; UTF-8 mode keeps non-ASCII paths readable
+pub fn use_c_locale(cmd: &mut Command) -> &mut Command {
+    cmd.env("LANG", "C")
+        .env("LC_ALL", "C")
+        .env("PYTHONUTF8", "1")
+}
+
+/// `configure_command` for the backend server, which also gets its own process group on Windows
+/// (to receive CTRL_BREAK) or its own session on Unix, so it can be shut down as a tree
+pub fn configure_backend_command(cmd: &mut Command) -> &mut Command {
+    #[cfg(windows)]
+    cmd.creation_flags(CREATE_NO_WINDOW | crate::shutdown::CREATE_NEW_PROCESS_GROUP);
+    #[cfg(unix)]
+    crate::shutdown::new_session(cmd);
+    cmd
+}
+
+/// Environment variables left out of `shell_command_line`, matched as substrings of the name
+const SECRET_ENV_MARKERS: &[&str] = &["SECRET", "PASSWORD", "TOKEN", "KEY"];
+
+/// The command as a line that can be pasted into a terminal (POSIX shell, or PowerShell on
+/// Windows) to run it by hand: working directory, explicitly set environment and arguments
+/// Secret-looking variables are omitted, so the backend falls back to its own defaults for them
+pub fn shell_command_line(cmd: &Command) -> String {
+    let mut parts = Vec::new();
+    if let Some(dir) = cmd.get_current_dir() {
+        parts.push(format!("cd {}", quote(&dir.to_string_lossy())));
+    }
+    let mut line = Vec::new();
+    for (name, value) in cmd.get_envs() {
+        let name = name.to_string_lossy();
+        let Some(value) = value else {
+            continue;
+        };
+        if SECRET_ENV_MARKERS
+            .iter()
+            .any(|marker| name.to_ascii_uppercase().contains(marker))
+        {
+            continue;
+        }
+        let value = quote(&value.to_string_lossy());
+        if cfg!(windows) {
+            parts.push(format!("$env:{} = {}", name, value));
+        } else {
+            line.push(format!("{}={}", name, value));
+        }
+    }
+    if cfg!(windows) {
+        line.push("&".to_string());
+    }
+    line.push(quote(&cmd.get_program().to_string_lossy()));
+    line.extend(cmd.get_args().map(|arg| quote(&arg.to_string_lossy())));
+    parts.push(line.join(" "));
+    parts.join(if cfg!(windows) { "; " } else { " && " })
+}
+
+/// Single-quote a word for the shell; both POSIX shells and PowerShell take single-quoted text literally
+fn quote(word: &str) -> String {
+    let plain = !word.is_empty()
+        && word
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@,+".contains(c));
+    if plain {
+        return word.to_string();
+    }
+    if cfg!(windows) {
+        format!("'{}'", word.replace('\'', "''"))
+    } else {
+        format!("'{}'", word.replace('\'', r"'\''"))
+    }
+}
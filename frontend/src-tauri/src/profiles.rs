@@ -0,0 +1,161 @@
+use crate::config::Config;
+use log::{info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+/// Profile backed by the original `db.sqlite3` in the app data directory
+pub const DEFAULT_PROFILE: &str = "default";
+
+/// Profile names become file names, so keep them to a safe character set
+pub fn validate_profile_name(name: &str) -> Result<(), String> {
+    let valid = !name.is_empty()
+        && name.len() <= 64
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if valid {
+        Ok(())
+    } else {
+        Err(format!(
+            "Invalid profile name '{}': use 1-64 letters, digits, '-' or '_'",
+            name
+        ))
+    }
+}
+
+/// Database file for a profile
+/// The default profile keeps the historical location so existing installs are unaffected
+pub fn profile_db_path(app_data_dir: &Path, name: &str) -> PathBuf {
+    if name == DEFAULT_PROFILE {
+        app_data_dir.join("db.sqlite3")
+    } else {
+        app_data_dir
+            .join("profiles")
+            .join(format!("{}.sqlite3", name))
+    }
+}
+
+/// Database path for the profile stored in the config, falling back to the default profile
+/// when the stored profile is invalid or its database no longer exists
+pub fn resolve_db_path(app_data_dir: &Path, config: &Config) -> PathBuf {
+    let name = config.active_profile.as_str();
+    if name != DEFAULT_PROFILE {
+        if let Err(e) = validate_profile_name(name) {
+            warn!("{}, using the default profile", e);
+        } else {
+            let path = profile_db_path(app_data_dir, name);
+            if path.exists() {
+                return path;
+            }
+            warn!(
+                "Database for profile '{}' not found at {:?}, using the default profile",
+                name, path
+            );
+        }
+    }
+    profile_db_path(app_data_dir, DEFAULT_PROFILE)
+}
+
+/// A profile and its database file
+#[derive(Debug, Clone, Serialize)]
+pub struct ProfileInfo {
+    pub name: String,
+    pub path: PathBuf,
+    /// Size of the database file in bytes
+    pub size: u64,
+    /// Whether the backend runs on this profile
+    pub active: bool,
+}
+
+/// The default profile and every `profiles/<name>.sqlite3` with a valid name
+pub fn list(app: &tauri::AppHandle) -> Result<Vec<ProfileInfo>, String> {
+    let app_data_dir = crate::storage::app_data_dir(app)?;
+    let active = crate::config::current(app).active_profile;
+    let mut names = vec![DEFAULT_PROFILE.to_string()];
+    let dir = app_data_dir.join("profiles");
+    match std::fs::read_dir(&dir) {
+        Ok(entries) => {
+            let mut found: Vec<String> = entries
+                .flatten()
+                .filter_map(|entry| {
+                    let name = entry
+                        .file_name()
+                        .to_str()?
+                        .strip_suffix(".sqlite3")?
+                        .to_string();
+                    (name != DEFAULT_PROFILE && validate_profile_name(&name).is_ok())
+                        .then_some(name)
+                })
+                .collect();
+            found.sort();
+            names.extend(found);
+        }
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => {
+            return Err(format!(
+                "Could not read profiles directory {:?}: {}",
+                dir, e
+            ));
+        }
+    }
+
+    Ok(names
+        .into_iter()
+        .map(|name| {
+            let path = profile_db_path(&app_data_dir, &name);
+            ProfileInfo {
+                size: std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0),
+                active: name == active,
+                name,
+                path,
+            }
+        })
+        .collect())
+}
+
+/// Remove a profile's database with its WAL/SHM/journal files and its backups
+/// The active and the default profile can't be deleted
+pub fn delete(app: &tauri::AppHandle, name: &str) -> Result<(), String> {
+    validate_profile_name(name)?;
+    if name == DEFAULT_PROFILE {
+        return Err("The default profile can't be deleted".to_string());
+    }
+    let app_data_dir = crate::storage::app_data_dir(app)?;
+    let path = profile_db_path(&app_data_dir, name);
+    if crate::config::current(app).active_profile == name
+        || crate::active_db_path(app).as_deref() == Some(path.as_path())
+    {
+        return Err(format!(
+            "Profile '{}' is in use; switch to another profile first",
+            name
+        ));
+    }
+    if !path.is_file() {
+        return Err(format!("Profile '{}' does not exist", name));
+    }
+
+    std::fs::remove_file(&path).map_err(|e| format!("Failed to delete {:?}: {}", path, e))?;
+    for suffix in ["-wal", "-shm", "-journal"] {
+        let mut sidecar = path.clone().into_os_string();
+        sidecar.push(suffix);
+        let sidecar = PathBuf::from(sidecar);
+        match std::fs::remove_file(&sidecar) {
+            Ok(()) => {}
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => warn!("Could not delete {:?}: {}", sidecar, e),
+        }
+    }
+
+    // Backups are matched by database name, which the default profile's `db.sqlite3` shares
+    // with a profile called "db"; those are kept rather than risk deleting the default's
+    let backups = if name == "db" {
+        warn!(
+            "Keeping backups of profile 'db', they can't be told apart from the default profile's"
+        );
+        0
+    } else {
+        crate::backups::delete_for_database(app, name)?
+    };
+    info!("Deleted profile '{}' and {} backup(s)", name, backups);
+    Ok(())
+}
@@ -0,0 +1,63 @@
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::Manager;
+
+/// Why the backend was started again
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartReason {
+    /// A failed spawn was retried during startup
+    SpawnRetry,
+    /// The watchdog found the backend alive but unresponsive
+    Watchdog,
+    /// The backend did not answer after the system resumed
+    Reconnect,
+    /// Requested by the user or a settings change (commands, profile switch, restore)
+    Manual,
+}
+
+/// Backend restarts in this app session, by reason
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RestartStats {
+    pub total: u32,
+    /// Restarts not asked for by the user; a high count means the backend is unstable
+    pub involuntary: u32,
+    pub spawn_retry: u32,
+    pub watchdog: u32,
+    pub reconnect: u32,
+    pub manual: u32,
+}
+
+/// Restart counts kept in app state
+#[derive(Default)]
+pub struct RestartCounter(Mutex<RestartStats>);
+
+/// Count a restart and emit `backend://restart_count` with the new totals
+pub fn record(app: &tauri::AppHandle, reason: RestartReason) {
+    let Some(state) = app.try_state::<RestartCounter>() else {
+        return;
+    };
+    let stats = {
+        let Ok(mut stats) = state.0.lock() else {
+            return;
+        };
+        stats.total += 1;
+        match reason {
+            RestartReason::SpawnRetry => stats.spawn_retry += 1,
+            RestartReason::Watchdog => stats.watchdog += 1,
+            RestartReason::Reconnect => stats.reconnect += 1,
+            RestartReason::Manual => stats.manual += 1,
+        }
+        if reason != RestartReason::Manual {
+            stats.involuntary += 1;
+        }
+        stats.clone()
+    };
+    crate::events::emit(app, "backend://restart_count", stats);
+}
+
+/// Restart counts so far
+pub fn stats(app: &tauri::AppHandle) -> RestartStats {
+    app.try_state::<RestartCounter>()
+        .and_then(|state| state.0.lock().ok().map(|stats| stats.clone()))
+        .unwrap_or_default()
+}
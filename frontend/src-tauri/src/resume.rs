@@ -0,0 +1,53 @@
+use log::{info, warn};
+use std::time::{Duration, Instant, SystemTime};
+
+/// How often the watcher wakes up to compare clocks
+const TICK: Duration = Duration::from_secs(5);
+
+/// Extra time beyond the tick that counts as a suspend rather than scheduling jitter
+const SUSPEND_THRESHOLD: Duration = Duration::from_secs(30);
+
+/// Watch for system suspend/resume and re-check the backend afterwards
+/// Resume is detected as a large gap between ticks: the wall clock keeps running while the system
+/// sleeps, and on platforms where the monotonic clock also does, the tick itself takes too long
+pub fn start_watcher(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_instant = Instant::now();
+        let mut last_wall = SystemTime::now();
+        loop {
+            std::thread::sleep(TICK);
+
+            let now_instant = Instant::now();
+            let now_wall = SystemTime::now();
+            let monotonic_gap = now_instant.duration_since(last_instant);
+            let wall_gap = now_wall.duration_since(last_wall).unwrap_or_default();
+            last_instant = now_instant;
+            last_wall = now_wall;
+
+            let gap = monotonic_gap.max(wall_gap);
+            if gap < TICK + SUSPEND_THRESHOLD {
+                continue;
+            }
+            info!(
+                "System resumed after about {}s, re-checking backend",
+                gap.as_secs()
+            );
+            if !crate::backend_started(&app) || crate::suspend::is_suspended(&app) {
+                continue;
+            }
+
+            let result = crate::reconnect_backend(&app);
+            if let Err(e) = &result {
+                warn!("Backend recovery after resume failed: {}", e);
+            }
+            crate::events::emit(
+                &app,
+                "backend://resumed",
+                serde_json::json!({
+                    "ok": result.is_ok(),
+                    "error": result.err(),
+                }),
+            );
+        }
+    });
+}
@@ -0,0 +1,70 @@
+use log::{info, warn};
+use std::path::PathBuf;
+
+/// File in the app data directory holding this install's Django `SECRET_KEY`
+const SECRET_FILE: &str = "secret_key";
+
+/// Characters Django's `get_random_secret_key` draws from
+const SECRET_CHARS: &[u8] = b"abcdefghijklmnopqrstuvwxyz0123456789!@#$%^&*(-_=+)";
+
+const SECRET_LENGTH: usize = 50;
+
+fn secret_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    crate::storage::app_data_dir(app).map(|dir| dir.join(SECRET_FILE))
+}
+
+/// A new random key in Django's format
+fn generate() -> Result<String, String> {
+    let mut key = String::with_capacity(SECRET_LENGTH);
+    let mut bytes = [0u8; 64];
+    while key.len() < SECRET_LENGTH {
+        getrandom::fill(&mut bytes).map_err(|e| format!("No system randomness: {}", e))?;
+        // Bytes past the largest multiple of the alphabet size are skipped to avoid bias
+        let limit = 256 - 256 % SECRET_CHARS.len();
+        key.extend(
+            bytes
+                .iter()
+                .filter(|byte| usize::from(**byte) < limit)
+                .map(|byte| char::from(SECRET_CHARS[usize::from(*byte) % SECRET_CHARS.len()]))
+                .take(SECRET_LENGTH - key.len()),
+        );
+    }
+    Ok(key)
+}
+
+/// Write the key readable only by the current user, replacing any previous one atomically
+fn write(path: &std::path::Path, key: &str) -> Result<(), String> {
+    let temp = path.with_extension("tmp");
+    std::fs::write(&temp, key).map_err(|e| format!("Failed to write {:?}: {}", temp, e))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let _ = std::fs::set_permissions(&temp, std::fs::Permissions::from_mode(0o600));
+    }
+    std::fs::rename(&temp, path).map_err(|e| format!("Failed to replace {:?}: {}", path, e))
+}
+
+/// This install's secret key, generated on first use
+pub fn load_or_create(app: &tauri::AppHandle) -> Result<String, String> {
+    let path = secret_path(app)?;
+    match std::fs::read_to_string(&path) {
+        Ok(key) if !key.trim().is_empty() => return Ok(key.trim().to_string()),
+        Ok(_) => warn!("Secret key file {:?} is empty, generating a new key", path),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            info!("Generating a secret key for this install")
+        }
+        Err(e) => return Err(format!("Failed to read {:?}: {}", path, e)),
+    }
+    let key = generate()?;
+    write(&path, &key)?;
+    Ok(key)
+}
+
+/// Replace the secret key; the backend must be restarted to use it
+/// Existing sessions and signed tokens become invalid
+pub fn rotate(app: &tauri::AppHandle) -> Result<(), String> {
+    let path = secret_path(app)?;
+    write(&path, &generate()?)?;
+    warn!("Secret key rotated, existing sessions will be invalidated");
+    Ok(())
+}
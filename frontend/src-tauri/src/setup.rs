@@ -0,0 +1,151 @@
+use log::{debug, info, warn};
+use std::io::{BufRead, BufReader, Read};
+use std::path::PathBuf;
+use std::process::{Child, Command, Stdio};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::Duration;
+use tauri::Manager;
+
+/// How often a running setup step is checked for completion or cancellation
+const STEP_POLL_INTERVAL: Duration = Duration::from_millis(200);
+
+/// The venv/pip process of the first-run setup, tracked so the user can cancel it
+#[derive(Default)]
+pub struct SetupState {
+    child: Mutex<Option<Child>>,
+    cancelled: AtomicBool,
+}
+
+/// Start a new setup run, clearing an earlier cancellation
+pub fn begin(app: &tauri::AppHandle) {
+    if let Some(state) = app.try_state::<SetupState>() {
+        state.cancelled.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Run one setup command to completion unless `cancel` is called meanwhile
+/// Returns whether the command succeeded
+pub fn run_step(app: &tauri::AppHandle, cmd: &mut Command) -> Result<bool, String> {
+    let Some(state) = app.try_state::<SetupState>() else {
+        return cmd
+            .status()
+            .map(|status| status.success())
+            .map_err(|e| e.to_string());
+    };
+    if state.cancelled.load(Ordering::SeqCst) {
+        return Err("Setup was cancelled".to_string());
+    }
+    {
+        let child = cmd.spawn().map_err(|e| e.to_string())?;
+        let mut tracked = state.child.lock().map_err(|e| e.to_string())?;
+        *tracked = Some(child);
+    }
+
+    loop {
+        {
+            let mut tracked = state.child.lock().map_err(|e| e.to_string())?;
+            // Taken by `cancel`, which also killed it
+            let Some(child) = tracked.as_mut() else {
+                return Err("Setup was cancelled".to_string());
+            };
+            match child.try_wait() {
+                Ok(Some(status)) => {
+                    *tracked = None;
+                    return Ok(status.success());
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    *tracked = None;
+                    return Err(e.to_string());
+                }
+            }
+        }
+        std::thread::sleep(STEP_POLL_INTERVAL);
+    }
+}
+
+/// Kill the running setup step and stop the remaining ones
+pub fn cancel(app: &tauri::AppHandle) -> Result<(), String> {
+    let state = app
+        .try_state::<SetupState>()
+        .ok_or("Setup state is not available")?;
+    state.cancelled.store(true, Ordering::SeqCst);
+    let child = state.child.lock().map_err(|e| e.to_string())?.take();
+    match child {
+        Some(mut child) => {
+            info!("Cancelling backend setup (PID {})", child.id());
+            if let Err(e) = child.kill() {
+                warn!("Could not kill setup process: {}", e);
+            }
+            let _ = child.wait();
+        }
+        None => info!("Cancelling backend setup (no step running)"),
+    }
+    crate::events::emit(app, "backend://setup_cancelled", ());
+    Ok(())
+}
+
+/// Download the backend's dependencies as wheels into `backend/wheels` for later offline installs
+/// Each line pip prints is emitted as `backend://cache_progress`; returns the wheels directory
+pub fn cache_dependencies(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let exe_dir = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(|dir| dir.to_path_buf()))
+        .ok_or("Could not resolve the executable directory")?;
+    let backend_dir = crate::find_backend_source_dir(&exe_dir)
+        .ok_or("Backend source directory (with requirements.txt) not found")?;
+    if !backend_dir.join("requirements.txt").is_file() {
+        return Err(format!("requirements.txt not found in {:?}", backend_dir));
+    }
+    let python = crate::find_python(&backend_dir).ok_or("Python not found")?;
+    let wheels_dir = backend_dir.join("wheels");
+    info!("Downloading backend dependencies into {:?}", wheels_dir);
+
+    let mut cmd = Command::new(&python);
+    cmd.args(["-m", "pip", "download", "-r", "requirements.txt", "-d"]);
+    cmd.arg(&wheels_dir);
+    cmd.current_dir(&backend_dir);
+    cmd.stdout(Stdio::piped());
+    cmd.stderr(Stdio::piped());
+    crate::process::configure_command(&mut cmd);
+    let mut child = cmd
+        .spawn()
+        .map_err(|e| format!("Failed to run pip: {}", e))?;
+
+    // Read stderr on its own thread so a chatty pip can't block on a full pipe
+    let stderr = child.stderr.take().map(|mut stderr| {
+        std::thread::spawn(move || {
+            let mut text = String::new();
+            let _ = stderr.read_to_string(&mut text);
+            text
+        })
+    });
+    if let Some(stdout) = child.stdout.take() {
+        for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+            debug!("pip: {}", line);
+            crate::events::emit_progress(
+                app,
+                "backend://cache_progress",
+                serde_json::json!({ "message": line }),
+            );
+        }
+    }
+    let status = child.wait().map_err(|e| e.to_string())?;
+    let stderr = stderr
+        .and_then(|reader| reader.join().ok())
+        .unwrap_or_default();
+    if !status.success() {
+        return Err(format!(
+            "pip download failed ({}): {}",
+            status,
+            stderr.trim()
+        ));
+    }
+    info!("Backend dependencies cached in {:?}", wheels_dir);
+    Ok(wheels_dir)
+}
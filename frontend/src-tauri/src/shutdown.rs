@@ -0,0 +1,155 @@
+use log::{debug, warn};
+use std::fmt;
+use std::process::{Child, ExitStatus};
+use std::time::{Duration, Instant};
+
+/// How long the backend gets to exit on its own before it is killed
+pub const GRACEFUL_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Windows process creation flag giving the backend its own process group,
+/// so a console control event can target it without reaching this app
+#[cfg(windows)]
+pub const CREATE_NEW_PROCESS_GROUP: u32 = 0x00000200;
+
+/// Exit code and terminating signal of a process, decoded from its `ExitStatus`
+/// On Unix a process killed by a signal (e.g. SIGKILL from the OOM killer) has no exit code
+#[derive(Debug, Clone, Copy)]
+pub struct ExitInfo {
+    pub code: Option<i32>,
+    pub signal: Option<i32>,
+}
+
+impl From<ExitStatus> for ExitInfo {
+    fn from(status: ExitStatus) -> Self {
+        #[cfg(unix)]
+        let signal = {
+            use std::os::unix::process::ExitStatusExt;
+            status.signal()
+        };
+        #[cfg(not(unix))]
+        let signal = None;
+
+        Self {
+            code: status.code(),
+            signal,
+        }
+    }
+}
+
+impl fmt::Display for ExitInfo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.code, self.signal) {
+            (Some(code), _) => write!(f, "exit code {}", code),
+            (None, Some(signal)) => match signal_name(signal) {
+                Some(name) => write!(f, "killed by signal {} ({})", signal, name),
+                None => write!(f, "killed by signal {}", signal),
+            },
+            (None, None) => write!(f, "unknown exit status"),
+        }
+    }
+}
+
+/// Name of the signals most likely to end the backend
+fn signal_name(signal: i32) -> Option<&'static str> {
+    #[cfg(unix)]
+    {
+        match signal {
+            libc::SIGKILL => Some("SIGKILL"),
+            libc::SIGTERM => Some("SIGTERM"),
+            libc::SIGINT => Some("SIGINT"),
+            libc::SIGSEGV => Some("SIGSEGV"),
+            libc::SIGABRT => Some("SIGABRT"),
+            _ => None,
+        }
+    }
+    #[cfg(not(unix))]
+    {
+        let _ = signal;
+        None
+    }
+}
+
+/// Poll the child until it exits or the timeout elapses
+pub fn wait_for_exit(child: &mut Child, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    loop {
+        match child.try_wait() {
+            Ok(Some(status)) => {
+                debug!("Backend process exited with status: {:?}", status);
+                return true;
+            }
+            Ok(None) if Instant::now() < deadline => {
+                std::thread::sleep(Duration::from_millis(100));
+            }
+            Ok(None) => return false,
+            Err(e) => {
+                warn!("Error waiting for backend process: {}", e);
+                return false;
+            }
+        }
+    }
+}
+
+/// Send CTRL_BREAK_EVENT to the process group led by `pid`
+/// The backend must have been spawned with `CREATE_NEW_PROCESS_GROUP` and a (hidden) console.
+/// This app is a GUI process without a console, so it briefly attaches to the backend's console
+/// and ignores the event itself while sending it
+#[cfg(windows)]
+pub fn send_ctrl_break(pid: u32) -> bool {
+    use windows_sys::Win32::System::Console::{
+        AttachConsole, CTRL_BREAK_EVENT, FreeConsole, GenerateConsoleCtrlEvent,
+        SetConsoleCtrlHandler,
+    };
+
+    // SAFETY: plain Win32 console calls with no pointers besides the null handler routine
+    unsafe {
+        FreeConsole();
+        if AttachConsole(pid) == 0 {
+            warn!("Could not attach to backend console (PID {})", pid);
+            return false;
+        }
+        SetConsoleCtrlHandler(None, 1);
+        let sent = GenerateConsoleCtrlEvent(CTRL_BREAK_EVENT, pid) != 0;
+        FreeConsole();
+        SetConsoleCtrlHandler(None, 0);
+        if !sent {
+            warn!("Could not send CTRL_BREAK to backend (PID {})", pid);
+        }
+        sent
+    }
+}
+
+/// Start the command in a new session (and process group) led by the spawned process,
+/// so the whole tree can be signalled with `signal_group`
+#[cfg(unix)]
+pub fn new_session(cmd: &mut std::process::Command) {
+    use std::os::unix::process::CommandExt;
+
+    // SAFETY: setsid is async-signal-safe and touches no memory of the parent
+    unsafe {
+        cmd.pre_exec(|| {
+            if libc::setsid() == -1 {
+                return Err(std::io::Error::last_os_error());
+            }
+            Ok(())
+        });
+    }
+}
+
+/// Send a signal to the process group led by `pid`
+#[cfg(unix)]
+pub fn signal_group(pid: u32, signal: libc::c_int) -> bool {
+    let Ok(pgid) = libc::pid_t::try_from(pid) else {
+        return false;
+    };
+    // SAFETY: kill has no memory safety requirements; a negative pid targets the process group
+    let sent = unsafe { libc::kill(-pgid, signal) } == 0;
+    if !sent {
+        debug!(
+            "Could not signal backend process group {}: {}",
+            pid,
+            std::io::Error::last_os_error()
+        );
+    }
+    sent
+}
@@ -0,0 +1,118 @@
+use log::info;
+use serde::Serialize;
+use std::path::Path;
+use tauri::Manager;
+
+#[cfg(any(windows, target_os = "macos"))]
+use std::process::Command;
+
+/// Result of checking an executable's code signature
+#[derive(Debug, Clone, Serialize)]
+pub struct SignatureCheck {
+    pub valid: bool,
+    /// Signing identity as reported by the platform tool
+    pub signer: Option<String>,
+    pub detail: String,
+}
+
+/// Whether `path` lies inside the app's bundled resources, which the installer already verified
+fn in_bundle(app: &tauri::AppHandle, path: &Path) -> bool {
+    let Ok(resource_dir) = app.path().resource_dir() else {
+        return false;
+    };
+    match (path.canonicalize(), resource_dir.canonicalize()) {
+        (Ok(path), Ok(resource_dir)) => path.starts_with(resource_dir),
+        _ => false,
+    }
+}
+
+/// Refuse to launch an executable found outside the bundle unless it is signed by `backend_signer`
+/// Only enforced with `verify_backend_signature`; platforms without a verifier fail closed
+pub fn ensure_trusted(app: &tauri::AppHandle, path: &Path) -> Result<(), String> {
+    let config = crate::config::current(app);
+    if !config.verify_backend_signature || in_bundle(app, path) {
+        return Ok(());
+    }
+    let check = verify(path)?;
+    if !check.valid {
+        return Err(format!(
+            "Refusing to launch {:?}: signature is not valid ({})",
+            path, check.detail
+        ));
+    }
+    if !config.backend_signer.is_empty()
+        && !check
+            .signer
+            .as_deref()
+            .is_some_and(|signer| signer.contains(&config.backend_signer))
+    {
+        return Err(format!(
+            "Refusing to launch {:?}: signed by {:?}, expected {:?}",
+            path, check.signer, config.backend_signer
+        ));
+    }
+    info!("Verified signature of {:?} ({:?})", path, check.signer);
+    Ok(())
+}
+
+/// Authenticode check through PowerShell's `Get-AuthenticodeSignature`
+#[cfg(windows)]
+pub fn verify(path: &Path) -> Result<SignatureCheck, String> {
+    // The path is passed through the environment so it never needs quoting in the script
+    let output = crate::process::configure_command(&mut Command::new("powershell"))
+        .args([
+            "-NoProfile",
+            "-NonInteractive",
+            "-Command",
+            "$s = Get-AuthenticodeSignature -LiteralPath $env:BUDGET_SIGNATURE_PATH; \
+             Write-Output $s.Status; Write-Output $s.SignerCertificate.Subject",
+        ])
+        .env("BUDGET_SIGNATURE_PATH", path)
+        .output()
+        .map_err(|e| format!("Could not run Get-AuthenticodeSignature: {}", e))?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut lines = stdout.lines().map(str::trim);
+    let status = lines.next().unwrap_or_default().to_string();
+    let signer = lines.next().filter(|s| !s.is_empty()).map(str::to_string);
+    Ok(SignatureCheck {
+        valid: output.status.success() && status == "Valid",
+        signer,
+        detail: status,
+    })
+}
+
+/// `codesign --verify`, with the signing authority taken from `codesign -dv`
+#[cfg(target_os = "macos")]
+pub fn verify(path: &Path) -> Result<SignatureCheck, String> {
+    let verify = crate::process::configure_command(&mut Command::new("codesign"))
+        .args(["--verify", "--strict"])
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Could not run codesign: {}", e))?;
+    // codesign writes its details to stderr
+    let details = crate::process::configure_command(&mut Command::new("codesign"))
+        .arg("-dv")
+        .arg("--verbose=2")
+        .arg(path)
+        .output()
+        .map_err(|e| format!("Could not run codesign: {}", e))?;
+    let details = String::from_utf8_lossy(&details.stderr);
+    let signer = details
+        .lines()
+        .find_map(|line| line.strip_prefix("Authority="))
+        .map(str::to_string);
+    Ok(SignatureCheck {
+        valid: verify.status.success(),
+        signer,
+        detail: String::from_utf8_lossy(&verify.stderr).trim().to_string(),
+    })
+}
+
+/// No platform code signing to check against
+#[cfg(not(any(windows, target_os = "macos")))]
+pub fn verify(path: &Path) -> Result<SignatureCheck, String> {
+    Err(format!(
+        "Signature verification of {:?} is not supported on this platform",
+        path
+    ))
+}
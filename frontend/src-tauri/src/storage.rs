@@ -0,0 +1,270 @@
+use crate::error::BackendError;
+use log::{error, info, warn};
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+use std::sync::{OnceLock, mpsc};
+use std::time::Duration;
+use tauri::Manager;
+
+/// How long a single filesystem operation may take before storage is considered unavailable
+const STORAGE_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Run a filesystem operation on a worker thread and give up after `STORAGE_TIMEOUT`
+/// A hung network mount leaves the worker blocked, but the caller gets an error instead of hanging
+pub fn with_timeout<T, F>(operation: &str, path: &Path, op: F) -> Result<T, BackendError>
+where
+    T: Send + 'static,
+    F: FnOnce() -> std::io::Result<T> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    std::thread::spawn(move || {
+        let _ = tx.send(op());
+    });
+
+    match rx.recv_timeout(STORAGE_TIMEOUT) {
+        Ok(Ok(value)) => Ok(value),
+        Ok(Err(e)) => Err(BackendError::Storage {
+            operation: operation.to_string(),
+            path: path.to_path_buf(),
+            message: e.to_string(),
+        }),
+        Err(_) => Err(BackendError::StorageUnavailable {
+            operation: operation.to_string(),
+            path: path.to_path_buf(),
+        }),
+    }
+}
+
+/// `Path::exists` with a deadline
+pub fn exists(path: &Path) -> Result<bool, BackendError> {
+    let owned: PathBuf = path.to_path_buf();
+    with_timeout("metadata", path, move || match std::fs::metadata(&owned) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+        Err(e) => Err(e),
+    })
+}
+
+/// `std::fs::create_dir_all` with a deadline
+pub fn create_dir_all(path: &Path) -> Result<(), BackendError> {
+    let owned = path.to_path_buf();
+    with_timeout("create directory", path, move || {
+        std::fs::create_dir_all(&owned)
+    })
+}
+
+/// Create an empty file with a deadline
+pub fn create_file(path: &Path) -> Result<(), BackendError> {
+    let owned = path.to_path_buf();
+    with_timeout("create file", path, move || {
+        std::fs::File::create(&owned).map(|_| ())
+    })
+}
+
+/// App data directory resolved once per run, possibly a fallback
+static APP_DATA_DIR: OnceLock<PathBuf> = OnceLock::new();
+
+/// Environment variable that turns on portable mode
+const PORTABLE_ENV: &str = "BUDGET_PORTABLE";
+
+/// Marker file next to the executable that turns on portable mode, e.g. on a USB stick
+const PORTABLE_MARKER: &str = "portable.txt";
+
+/// Portable mode as detected at startup
+#[derive(Debug, Clone)]
+enum Portable {
+    Off,
+    /// Data lives in this directory next to the executable
+    Active(PathBuf),
+    /// Requested, but the directory next to the executable can't be written to
+    Unwritable(PathBuf),
+}
+
+/// Whether portable mode was requested (`BUDGET_PORTABLE=1` or `portable.txt` next to the
+/// executable) and where its data goes; detected once, before logging is set up
+fn portable() -> &'static Portable {
+    static PORTABLE: OnceLock<Portable> = OnceLock::new();
+    PORTABLE.get_or_init(|| {
+        let Some(exe_dir) = std::env::current_exe()
+            .ok()
+            .and_then(|exe| exe.parent().map(Path::to_path_buf))
+        else {
+            return Portable::Off;
+        };
+        let requested = std::env::var(PORTABLE_ENV).ok().as_deref() == Some("1")
+            || exe_dir.join(PORTABLE_MARKER).is_file();
+        if !requested {
+            return Portable::Off;
+        }
+        let dir = exe_dir.join("data");
+        if is_writable(&dir) {
+            Portable::Active(dir)
+        } else {
+            Portable::Unwritable(dir)
+        }
+    })
+}
+
+/// Data directory next to the executable when portable mode is active
+pub fn portable_dir() -> Option<&'static Path> {
+    match portable() {
+        Portable::Active(dir) => Some(dir),
+        _ => None,
+    }
+}
+
+/// Log whether portable mode is in use, once logging is up
+/// A portable directory that can't be written to is reported with `backend://portable_unavailable`
+/// and the regular app data directory is used instead
+pub fn report_portable_mode(app: &tauri::AppHandle) {
+    match portable() {
+        Portable::Off => {}
+        Portable::Active(dir) => info!("Portable mode: keeping all data in {:?}", dir),
+        Portable::Unwritable(dir) => {
+            error!(
+                "Portable mode was requested but {:?} is not writable (read-only drive?), using the app data directory instead",
+                dir
+            );
+            crate::events::emit(
+                app,
+                "backend://portable_unavailable",
+                serde_json::json!({ "path": dir }),
+            );
+        }
+    }
+}
+
+/// Directory for the app log: `logs` in the portable directory, else the platform log directory
+pub fn log_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    match portable_dir() {
+        Some(dir) => Ok(dir.join("logs")),
+        None => app
+            .path()
+            .app_log_dir()
+            .map_err(|e| format!("Could not resolve log directory: {}", e)),
+    }
+}
+
+/// Whether files can be created in `dir` (created if missing)
+fn is_writable(dir: &Path) -> bool {
+    let probe = dir.join(".write-test");
+    let writable = std::fs::create_dir_all(dir).is_ok() && std::fs::write(&probe, b"").is_ok();
+    let _ = std::fs::remove_file(&probe);
+    writable
+}
+
+/// The app data directory, resolved (and created) once and reused for the rest of the run
+/// In portable mode this is the directory next to the executable. Otherwise resolution is
+/// retried once; if it still fails, the first writable fallback (the working directory, then
+/// the temp directory) is used, logged prominently and reported with `backend://storage_fallback`
+/// so users can find their data
+pub fn app_data_dir(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    if let Some(dir) = APP_DATA_DIR.get() {
+        return Ok(dir.clone());
+    }
+    if let Some(dir) = portable_dir() {
+        return Ok(APP_DATA_DIR.get_or_init(|| dir.to_path_buf()).clone());
+    }
+    let resolved = app.path().app_data_dir().or_else(|e| {
+        warn!("Failed to get app data directory ({}), retrying", e);
+        std::thread::sleep(Duration::from_millis(200));
+        app.path().app_data_dir()
+    });
+    let dir = match resolved {
+        Ok(dir) => {
+            if let Err(e) = std::fs::create_dir_all(&dir) {
+                warn!("Could not create app data directory {:?}: {}", dir, e);
+            }
+            dir
+        }
+        Err(e) => {
+            let fallback = std::env::current_dir()
+                .into_iter()
+                .chain([std::env::temp_dir().join("budget-planer")])
+                .find(|dir| is_writable(dir))
+                .ok_or_else(|| {
+                    format!(
+                        "App data directory is unavailable ({}) and no writable fallback was found",
+                        e
+                    )
+                })?;
+            error!("==============================================================");
+            error!("App data directory is unavailable: {}", e);
+            error!(
+                "Using fallback directory {:?} for the database and data",
+                fallback
+            );
+            error!("==============================================================");
+            crate::diagnostics::record_startup_info(app, |info| {
+                info.storage_fallback = Some(fallback.clone())
+            });
+            crate::events::emit(
+                app,
+                "backend://storage_fallback",
+                serde_json::json!({ "path": fallback, "reason": e.to_string() }),
+            );
+            fallback
+        }
+    };
+    info!("App data directory: {:?}", dir);
+    Ok(APP_DATA_DIR.get_or_init(|| dir).clone())
+}
+
+/// Bytes used on disk by the app's data, broken down by kind
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct StorageUsage {
+    /// Active database file
+    pub database: u64,
+    /// SQLite write-ahead log (`-wal`) of the active database
+    pub wal: u64,
+    /// SQLite shared-memory index (`-shm`) of the active database
+    pub shm: u64,
+    pub backups: u64,
+    pub logs: u64,
+    /// Everything under the app data directory, including the above where they live there
+    pub app_data: u64,
+}
+
+/// Total size of the files under `path`, recursively; symlinks are not followed
+fn dir_size(path: &Path) -> u64 {
+    let Ok(entries) = std::fs::read_dir(path) else {
+        return 0;
+    };
+    entries
+        .flatten()
+        .map(|entry| match entry.file_type() {
+            Ok(kind) if kind.is_dir() => dir_size(&entry.path()),
+            Ok(kind) if kind.is_file() => entry.metadata().map(|m| m.len()).unwrap_or(0),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn file_size(path: &Path) -> u64 {
+    std::fs::metadata(path).map(|m| m.len()).unwrap_or(0)
+}
+
+/// Measure the database, its WAL/SHM files, backups, logs and the whole app data directory
+pub fn usage(app: &tauri::AppHandle) -> StorageUsage {
+    let mut usage = StorageUsage::default();
+    if let Some(db_path) = crate::active_db_path(app) {
+        let sidecar = |suffix: &str| {
+            let mut name = db_path.clone().into_os_string();
+            name.push(suffix);
+            PathBuf::from(name)
+        };
+        usage.database = file_size(&db_path);
+        usage.wal = file_size(&sidecar("-wal"));
+        usage.shm = file_size(&sidecar("-shm"));
+    }
+    if let Ok(dir) = crate::backups::backups_dir(app) {
+        usage.backups = dir_size(&dir);
+    }
+    if let Ok(dir) = log_dir(app) {
+        usage.logs = dir_size(&dir);
+    }
+    if let Ok(dir) = app_data_dir(app) {
+        usage.app_data = dir_size(&dir);
+    }
+    usage
+}
@@ -0,0 +1,60 @@
+use log::{info, warn};
+use std::io::Write;
+use std::path::Path;
+use tauri::Manager;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+/// Write a zip with everything useful for a bug report: the app log, the effective config,
+/// the environment report and the startup diagnostics
+/// The user's home directory is replaced by `~` so usernames don't end up in public issues
+pub fn export_bundle(app: &tauri::AppHandle, dest: &Path) -> Result<(), String> {
+    let home = app
+        .path()
+        .home_dir()
+        .ok()
+        .map(|dir| dir.to_string_lossy().to_string());
+    let redact = |text: String| match &home {
+        Some(home) if !home.is_empty() => text.replace(home.as_str(), "~"),
+        _ => text,
+    };
+
+    let mut files: Vec<(&str, String)> = Vec::new();
+    match crate::logging::app_log_path(app).and_then(|path| {
+        std::fs::read(&path).map_err(|e| format!("Could not read {:?}: {}", path, e))
+    }) {
+        Ok(log) => files.push(("app.log", String::from_utf8_lossy(&log).to_string())),
+        Err(e) => warn!("Support bundle will not include the app log: {}", e),
+    }
+    // The config holds no secrets, so it is included as-is
+    let config = toml::to_string_pretty(&crate::config::current(app)).map_err(|e| e.to_string())?;
+    files.push(("config.toml", config));
+    files.push(("environment.md", crate::diagnostics::build_env_report(app)));
+    let timeline = app
+        .try_state::<crate::timing::StartupTimeline>()
+        .map(|timeline| timeline.snapshot())
+        .unwrap_or_default();
+    let startup = serde_json::json!({
+        "startup_info": crate::diagnostics::startup_info(app),
+        "timeline": timeline,
+    });
+    files.push((
+        "startup.json",
+        serde_json::to_string_pretty(&startup).map_err(|e| e.to_string())?,
+    ));
+
+    let file =
+        std::fs::File::create(dest).map_err(|e| format!("Could not create {:?}: {}", dest, e))?;
+    let mut zip = ZipWriter::new(file);
+    let options = SimpleFileOptions::default().compression_method(zip::CompressionMethod::Deflated);
+    for (name, contents) in files {
+        zip.start_file(name, options)
+            .map_err(|e| format!("Failed to add {} to support bundle: {}", name, e))?;
+        zip.write_all(redact(contents).as_bytes())
+            .map_err(|e| format!("Failed to write {} to support bundle: {}", name, e))?;
+    }
+    zip.finish()
+        .map_err(|e| format!("Failed to write support bundle: {}", e))?;
+    info!("Exported support bundle to {:?}", dest);
+    Ok(())
+}
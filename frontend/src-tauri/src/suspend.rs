@@ -0,0 +1,154 @@
+use log::{info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::Manager;
+
+/// Whether the backend process is currently suspended (low power mode)
+/// Health checks and requests must wait until it is resumed
+#[derive(Default)]
+pub struct BackendSuspended(AtomicBool);
+
+/// Whether the backend is currently suspended
+pub fn is_suspended(app: &tauri::AppHandle) -> bool {
+    app.try_state::<BackendSuspended>()
+        .is_some_and(|state| state.0.load(Ordering::SeqCst))
+}
+
+fn set_suspended(app: &tauri::AppHandle, suspended: bool) {
+    if let Some(state) = app.try_state::<BackendSuspended>() {
+        state.0.store(suspended, Ordering::SeqCst);
+    }
+    crate::events::emit(
+        app,
+        "backend://suspend_changed",
+        serde_json::json!({ "suspended": suspended }),
+    );
+}
+
+/// Freeze the backend process tree without stopping it
+pub fn suspend(app: &tauri::AppHandle) -> Result<(), String> {
+    if is_suspended(app) {
+        return Ok(());
+    }
+    let pid = crate::backend_pid(app).ok_or("The backend is not running")?;
+    set_process_tree_suspended(pid, true)?;
+    info!("Backend suspended (PID {})", pid);
+    set_suspended(app, true);
+    Ok(())
+}
+
+/// Let a suspended backend run again
+pub fn resume(app: &tauri::AppHandle) -> Result<(), String> {
+    if !is_suspended(app) {
+        return Ok(());
+    }
+    // A backend that went away while suspended has nothing left to resume
+    if let Some(pid) = crate::backend_pid(app) {
+        set_process_tree_suspended(pid, false)?;
+        info!("Backend resumed (PID {})", pid);
+    }
+    set_suspended(app, false);
+    Ok(())
+}
+
+/// Resume a suspended backend before it is stopped, so it can still shut down gracefully
+pub fn resume_before_stop(app: &tauri::AppHandle) {
+    if let Err(e) = resume(app) {
+        warn!("Could not resume backend before stopping it: {}", e);
+    }
+}
+
+/// The backend runs in its own process group, so stopping the group covers runserver's children
+#[cfg(unix)]
+fn set_process_tree_suspended(pid: u32, suspended: bool) -> Result<(), String> {
+    let signal = if suspended {
+        libc::SIGSTOP
+    } else {
+        libc::SIGCONT
+    };
+    if crate::shutdown::signal_group(pid, signal) {
+        Ok(())
+    } else {
+        Err(format!(
+            "Could not {} backend process group {}",
+            if suspended { "suspend" } else { "resume" },
+            pid
+        ))
+    }
+}
+
+/// Windows has no process-level stop signal, so every thread of the backend and its
+/// descendants (the PyInstaller bootloader runs Python as a child) is suspended individually
+#[cfg(windows)]
+fn set_process_tree_suspended(pid: u32, suspended: bool) -> Result<(), String> {
+    use std::collections::HashSet;
+    use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+    use windows_sys::Win32::Foundation::{CloseHandle, INVALID_HANDLE_VALUE};
+    use windows_sys::Win32::System::Diagnostics::ToolHelp::{
+        CreateToolhelp32Snapshot, TH32CS_SNAPTHREAD, THREADENTRY32, Thread32First, Thread32Next,
+    };
+    use windows_sys::Win32::System::Threading::{
+        OpenThread, ResumeThread, SuspendThread, THREAD_SUSPEND_RESUME,
+    };
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(ProcessesToUpdate::All, true, ProcessRefreshKind::nothing());
+    let mut tree: HashSet<u32> = HashSet::from([pid]);
+    loop {
+        let before = tree.len();
+        for (child, process) in system.processes() {
+            if process
+                .parent()
+                .is_some_and(|parent| tree.contains(&parent.as_u32()))
+            {
+                tree.insert(child.as_u32());
+            }
+        }
+        if tree.len() == before {
+            break;
+        }
+    }
+
+    // SAFETY: the snapshot and thread handles are checked and closed; THREADENTRY32 is plain data
+    // whose dwSize is set before use as the API requires
+    unsafe {
+        let snapshot = CreateToolhelp32Snapshot(TH32CS_SNAPTHREAD, 0);
+        if snapshot == INVALID_HANDLE_VALUE {
+            return Err(format!(
+                "Could not list backend threads: {}",
+                std::io::Error::last_os_error()
+            ));
+        }
+        let mut entry: THREADENTRY32 = std::mem::zeroed();
+        entry.dwSize = std::mem::size_of::<THREADENTRY32>() as u32;
+        let mut failed = 0;
+        let mut more = Thread32First(snapshot, &mut entry) != 0;
+        while more {
+            if tree.contains(&entry.th32OwnerProcessID) {
+                let thread = OpenThread(THREAD_SUSPEND_RESUME, 0, entry.th32ThreadID);
+                if thread.is_null() {
+                    failed += 1;
+                } else {
+                    let result = if suspended {
+                        SuspendThread(thread)
+                    } else {
+                        ResumeThread(thread)
+                    };
+                    if result == u32::MAX {
+                        failed += 1;
+                    }
+                    CloseHandle(thread);
+                }
+            }
+            more = Thread32Next(snapshot, &mut entry) != 0;
+        }
+        CloseHandle(snapshot);
+        if failed > 0 {
+            return Err(format!(
+                "Could not {} {} backend thread(s)",
+                if suspended { "suspend" } else { "resume" },
+                failed
+            ));
+        }
+    }
+    Ok(())
+}
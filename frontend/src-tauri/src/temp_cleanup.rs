@@ -0,0 +1,84 @@
+use log::{debug, info, warn};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+/// Extraction directories younger than this are never touched, so a live extraction is safe
+const STALE_AFTER: Duration = Duration::from_secs(24 * 3600);
+
+/// Remove `_MEI*` directories left in the temp dir by crashed runs of the bundled backend
+/// Only directories that are old, contain our Django bundle, belong to the current user and are
+/// not referenced by any running process are deleted
+pub fn remove_stale_extractions() {
+    let temp_dir = std::env::temp_dir();
+    let Ok(entries) = std::fs::read_dir(&temp_dir) else {
+        return;
+    };
+
+    let candidates: Vec<PathBuf> = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| is_stale_backend_extraction(path))
+        .collect();
+    if candidates.is_empty() {
+        return;
+    }
+
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_cwd(UpdateKind::OnlyIfNotSet)
+            .with_environ(UpdateKind::OnlyIfNotSet),
+    );
+
+    for dir in candidates {
+        let in_use = system.processes().values().any(|process| {
+            process.exe().is_some_and(|exe| exe.starts_with(&dir))
+                || process.cwd().is_some_and(|cwd| cwd.starts_with(&dir))
+                || process
+                    .environ()
+                    .iter()
+                    .any(|var| var.to_string_lossy().contains(&*dir.to_string_lossy()))
+        });
+        if in_use {
+            debug!("Keeping {:?}: still used by a running process", dir);
+            continue;
+        }
+        match std::fs::remove_dir_all(&dir) {
+            Ok(()) => info!("Removed stale backend extraction {:?}", dir),
+            Err(e) => warn!("Failed to remove stale backend extraction {:?}: {}", dir, e),
+        }
+    }
+}
+
+/// Old `_MEI*` directory holding our backend's Django bundle and owned by this user
+fn is_stale_backend_extraction(path: &Path) -> bool {
+    let is_mei = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.starts_with("_MEI"));
+    let Ok(metadata) = std::fs::symlink_metadata(path) else {
+        return false;
+    };
+    if !is_mei || !metadata.is_dir() || !path.join("django").is_dir() {
+        return false;
+    }
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::MetadataExt;
+        // SAFETY: getuid has no preconditions
+        if metadata.uid() != unsafe { libc::getuid() } {
+            return false;
+        }
+    }
+
+    metadata
+        .modified()
+        .ok()
+        .and_then(|modified| SystemTime::now().duration_since(modified).ok())
+        .is_some_and(|age| age > STALE_AFTER)
+}
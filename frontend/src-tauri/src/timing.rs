@@ -0,0 +1,175 @@
+use log::{debug, warn};
+use serde::Serialize;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tauri::Manager;
+
+/// Phases shorter than this are never flagged as waiting on I/O
+const IO_WAIT_MIN_DURATION: Duration = Duration::from_secs(2);
+
+/// A phase whose CPU time is below this share of its wall time was mostly waiting (disk contention)
+const IO_WAIT_MAX_CPU_SHARE: f64 = 0.25;
+
+/// Duration of a single named startup phase
+#[derive(Debug, Clone, Serialize)]
+pub struct PhaseTiming {
+    pub name: String,
+    pub duration_ms: u64,
+    /// CPU time spent during the phase, when measured (`startup_io_diagnostics`)
+    pub cpu_ms: Option<u64>,
+    /// Long phase that spent most of its time waiting rather than computing
+    pub io_bound: bool,
+}
+
+/// Whose CPU time a measured phase is compared against
+#[derive(Debug, Clone, Copy)]
+pub enum CpuScope {
+    /// Work done on this process's threads (e.g. discovery's file probing)
+    Process,
+    /// Work done by child processes waited for during the phase (e.g. `migrate`); Unix only
+    Children,
+}
+
+/// Measures one startup phase from creation until `finish` is called
+pub struct PhaseTimer {
+    name: &'static str,
+    start: Instant,
+    cpu: Option<(CpuScope, Duration)>,
+}
+
+impl PhaseTimer {
+    pub fn start(name: &'static str) -> Self {
+        Self {
+            name,
+            start: Instant::now(),
+            cpu: None,
+        }
+    }
+
+    /// Like `start`, but also record CPU time when `startup_io_diagnostics` is enabled,
+    /// so a slow phase can be told apart as disk-bound rather than inefficient
+    pub fn start_measured(app: &tauri::AppHandle, name: &'static str, scope: CpuScope) -> Self {
+        let cpu = crate::config::current(app)
+            .startup_io_diagnostics
+            .then(|| cpu_time(scope).map(|used| (scope, used)))
+            .flatten();
+        Self {
+            cpu,
+            ..Self::start(name)
+        }
+    }
+
+    /// Stop the timer and append the result to the app's startup timeline
+    pub fn finish(self, app: &tauri::AppHandle) -> PhaseTiming {
+        let elapsed = self.start.elapsed();
+        let cpu = self
+            .cpu
+            .and_then(|(scope, before)| cpu_time(scope).map(|after| after.saturating_sub(before)));
+        let io_bound = cpu.is_some_and(|cpu| {
+            elapsed >= IO_WAIT_MIN_DURATION
+                && cpu.as_secs_f64() < elapsed.as_secs_f64() * IO_WAIT_MAX_CPU_SHARE
+        });
+        let timing = PhaseTiming {
+            name: self.name.to_string(),
+            duration_ms: elapsed.as_millis() as u64,
+            cpu_ms: cpu.map(|cpu| cpu.as_millis() as u64),
+            io_bound,
+        };
+        debug!("Phase '{}' took {}ms", timing.name, timing.duration_ms);
+        if io_bound {
+            warn!(
+                "Phase '{}' took {}ms but used only {}ms of CPU, it was likely waiting on disk I/O",
+                timing.name,
+                timing.duration_ms,
+                timing.cpu_ms.unwrap_or(0)
+            );
+        }
+        if let Some(timeline) = app.try_state::<StartupTimeline>() {
+            timeline.record(timing.clone());
+        }
+        timing
+    }
+}
+
+/// Phase timings of the most recent backend startup, kept in app state
+#[derive(Default)]
+pub struct StartupTimeline(Mutex<Vec<PhaseTiming>>);
+
+impl StartupTimeline {
+    /// Clear the timeline at the beginning of a new startup
+    pub fn reset(&self) {
+        if let Ok(mut phases) = self.0.lock() {
+            phases.clear();
+        }
+    }
+
+    pub fn record(&self, timing: PhaseTiming) {
+        if let Ok(mut phases) = self.0.lock() {
+            phases.push(timing);
+        }
+    }
+
+    pub fn snapshot(&self) -> Vec<PhaseTiming> {
+        self.0.lock().map(|p| p.clone()).unwrap_or_default()
+    }
+}
+
+/// CPU time (user + system) used so far by this process or its waited-for children
+#[cfg(unix)]
+fn cpu_time(scope: CpuScope) -> Option<Duration> {
+    let who = match scope {
+        CpuScope::Process => libc::RUSAGE_SELF,
+        CpuScope::Children => libc::RUSAGE_CHILDREN,
+    };
+    // SAFETY: getrusage only writes into the zeroed struct we pass
+    let usage = unsafe {
+        let mut usage: libc::rusage = std::mem::zeroed();
+        if libc::getrusage(who, &mut usage) != 0 {
+            return None;
+        }
+        usage
+    };
+    let to_duration = |time: libc::timeval| {
+        Duration::from_secs(time.tv_sec as u64) + Duration::from_micros(time.tv_usec as u64)
+    };
+    Some(to_duration(usage.ru_utime) + to_duration(usage.ru_stime))
+}
+
+/// CPU time (kernel + user) used so far by this process; children are not measurable here
+#[cfg(windows)]
+fn cpu_time(scope: CpuScope) -> Option<Duration> {
+    use windows_sys::Win32::Foundation::FILETIME;
+    use windows_sys::Win32::System::Threading::{GetCurrentProcess, GetProcessTimes};
+
+    if matches!(scope, CpuScope::Children) {
+        return None;
+    }
+    let empty = || FILETIME {
+        dwLowDateTime: 0,
+        dwHighDateTime: 0,
+    };
+    let (mut creation, mut exit, mut kernel, mut user) = (empty(), empty(), empty(), empty());
+    // SAFETY: the pseudo handle needs no closing and the FILETIMEs outlive the call
+    let ok = unsafe {
+        GetProcessTimes(
+            GetCurrentProcess(),
+            &mut creation,
+            &mut exit,
+            &mut kernel,
+            &mut user,
+        )
+    };
+    if ok == 0 {
+        return None;
+    }
+    // FILETIME counts 100ns intervals
+    let ticks = |time: FILETIME| {
+        (u64::from(time.dwHighDateTime) << 32 | u64::from(time.dwLowDateTime)) * 100
+    };
+    Some(Duration::from_nanos(ticks(kernel) + ticks(user)))
+}
+
+#[cfg(not(any(unix, windows)))]
+fn cpu_time(_scope: CpuScope) -> Option<Duration> {
+    None
+}
@@ -0,0 +1,130 @@
+use log::{debug, info, warn};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How often the scheduler wakes up to check whether a checkpoint is due
+const SCHEDULER_TICK: Duration = Duration::from_secs(60);
+
+/// How long a checkpoint waits for the backend's write lock before giving up
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// How long recovery waits for a backend that is still exiting to close the database
+const RECOVERY_LOCK_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// Copy the write-ahead log into the database and truncate the `-wal` file
+/// A database not in WAL mode has nothing to checkpoint and succeeds unchanged
+pub fn checkpoint(db_path: &Path) -> Result<(), String> {
+    let connection = rusqlite::Connection::open(db_path)
+        .map_err(|e| format!("Could not open database {:?}: {}", db_path, e))?;
+    connection
+        .busy_timeout(BUSY_TIMEOUT)
+        .map_err(|e| e.to_string())?;
+    // Returns (busy, log frames, checkpointed frames); busy means a reader or writer was in the way
+    let (busy, log_frames, checkpointed): (i64, i64, i64) = connection
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .map_err(|e| format!("WAL checkpoint of {:?} failed: {}", db_path, e))?;
+    if busy != 0 {
+        return Err(format!(
+            "WAL checkpoint of {:?} was blocked by an open transaction",
+            db_path
+        ));
+    }
+    info!(
+        "WAL checkpoint of {:?}: {} of {} frame(s) checkpointed",
+        db_path, checkpointed, log_frames
+    );
+    Ok(())
+}
+
+/// Fold a `-wal` / `-shm` pair left behind by a crash back into the database and remove it
+/// Nothing is touched while any other connection has the database open; returns whether there
+/// was anything to recover
+pub fn recover_orphaned(db_path: &Path) -> Result<bool, String> {
+    let sidecar = |suffix: &str| {
+        let mut path = db_path.as_os_str().to_owned();
+        path.push(suffix);
+        std::path::PathBuf::from(path)
+    };
+    let (wal, shm) = (sidecar("-wal"), sidecar("-shm"));
+    if !wal.exists() && !shm.exists() {
+        return Ok(false);
+    }
+
+    // In exclusive locking mode SQLite keeps the database lock instead of sharing the `-shm`
+    // index, so taking it fails while a backend (a live one, one still exiting, or another
+    // app instance's) has the database open, and nobody can open it until recovery is done
+    let connection = rusqlite::Connection::open(db_path)
+        .map_err(|e| format!("Could not open database {:?}: {}", db_path, e))?;
+    connection
+        .busy_timeout(RECOVERY_LOCK_TIMEOUT)
+        .map_err(|e| e.to_string())?;
+    match connection.execute_batch("PRAGMA locking_mode = EXCLUSIVE; BEGIN EXCLUSIVE; COMMIT;") {
+        Ok(()) => {}
+        Err(rusqlite::Error::SqliteFailure(error, _))
+            if error.code == rusqlite::ErrorCode::DatabaseBusy =>
+        {
+            debug!("{:?} is in use, leaving its WAL files alone", db_path);
+            return Ok(false);
+        }
+        Err(e) => return Err(format!("Could not lock {:?}: {}", db_path, e)),
+    }
+
+    warn!(
+        "Found leftover WAL files for {:?} with the database not in use, recovering them",
+        db_path
+    );
+    // Opening the database replays the log; the checkpoint writes it into the main file
+    let (busy, _, _): (i64, i64, i64) = connection
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| {
+            Ok((row.get(0)?, row.get(1)?, row.get(2)?))
+        })
+        .map_err(|e| format!("WAL checkpoint of {:?} failed: {}", db_path, e))?;
+    if busy != 0 {
+        return Err(format!("WAL checkpoint of {:?} was blocked", db_path));
+    }
+    drop(connection);
+    for path in [&wal, &shm] {
+        let remove = match std::fs::metadata(path) {
+            // A non-empty log still holds changes the checkpoint didn't write back
+            Ok(metadata) => path == &shm || metadata.len() == 0,
+            Err(_) => false,
+        };
+        if remove {
+            std::fs::remove_file(path)
+                .map_err(|e| format!("Could not remove {:?}: {}", path, e))?;
+        }
+    }
+    info!("Recovered leftover WAL files of {:?}", db_path);
+    Ok(true)
+}
+
+/// Start the background thread for automatic checkpoints
+/// The interval is re-read from the config on every tick, so changes apply without a restart
+pub fn start_scheduler(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut last_checkpoint = Instant::now();
+        loop {
+            std::thread::sleep(SCHEDULER_TICK);
+
+            let minutes = crate::config::current(&app).wal_checkpoint_interval_minutes;
+            if minutes == 0 || last_checkpoint.elapsed() < Duration::from_secs(minutes * 60) {
+                continue;
+            }
+            last_checkpoint = Instant::now();
+
+            // A suspended backend may hold the write lock until it is resumed
+            if crate::config::current(&app).read_only || crate::suspend::is_suspended(&app) {
+                debug!("Backend suspended, skipping WAL checkpoint");
+                continue;
+            }
+            let Some(db_path) = crate::active_db_path(&app) else {
+                continue;
+            };
+            if let Err(e) = checkpoint(&db_path) {
+                warn!("Automatic WAL checkpoint failed: {}", e);
+            }
+        }
+    });
+}
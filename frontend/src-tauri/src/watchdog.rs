@@ -0,0 +1,66 @@
+use log::{debug, error, info, warn};
+use std::time::Duration;
+
+/// How often the running backend is probed
+const PROBE_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Consecutive failed probes against a live process before it is considered wedged
+const MAX_FAILED_PROBES: u32 = 3;
+
+/// Probe the backend periodically and restart it when it is alive but no longer answering
+/// (deadlocks, hangs); a backend that exited is left to the exit reporting
+pub fn start(app: tauri::AppHandle) {
+    std::thread::spawn(move || {
+        let mut failed_probes = 0;
+        loop {
+            std::thread::sleep(PROBE_INTERVAL);
+            // A suspended backend can't answer and must not be restarted behind the user's back
+            if crate::suspend::is_suspended(&app) || !crate::backend_alive(&app) {
+                failed_probes = 0;
+                continue;
+            }
+
+            // Fetched on every probe so a reloaded `backend_tls` reaches the watchdog too
+            let client = match crate::health_client::shared(&app) {
+                Ok(client) => client,
+                Err(e) => {
+                    error!("Watchdog has no health client: {}", e);
+                    continue;
+                }
+            };
+            let url = crate::health_url(&app, crate::port::current_port(&app));
+            match crate::probe_health(&app, &client, &url, &crate::config::current(&app)) {
+                Ok(_) => failed_probes = 0,
+                Err(reason) => {
+                    failed_probes += 1;
+                    debug!(
+                        "Watchdog probe {}/{} failed: {}",
+                        failed_probes, MAX_FAILED_PROBES, reason
+                    );
+                }
+            }
+            if failed_probes < MAX_FAILED_PROBES {
+                continue;
+            }
+
+            failed_probes = 0;
+            warn!(
+                "Backend is running but failed {} health checks in a row, restarting it",
+                MAX_FAILED_PROBES
+            );
+            let result = crate::restart_backend_for(&app, crate::restarts::RestartReason::Watchdog);
+            match &result {
+                Ok(()) => info!("Backend recovered"),
+                Err(e) => error!("Backend recovery failed: {}", e),
+            }
+            crate::events::emit(
+                &app,
+                "backend://recovered",
+                serde_json::json!({
+                    "ok": result.is_ok(),
+                    "error": result.err(),
+                }),
+            );
+        }
+    });
+}
@@ -1,5 +1,7 @@
-import { useState, useEffect, useCallback } from 'react'
+import { useState, useEffect, useCallback, useRef } from 'react'
 import { useTranslation } from 'react-i18next'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 import { budgetApi } from '../services/api'
 import { useDarkMode } from '../contexts/DarkModeContext'
 
@@ -17,6 +19,13 @@ type LoadingStatus =
   | 'connecting'
   | 'ready'
   | 'error'
+  | 'startup_failed'
+
+interface StartupFailure {
+  code: string
+  message: string
+  known_good_available?: boolean
+}
 
 interface StatusMessage {
   status: LoadingStatus
@@ -43,6 +52,28 @@ function LoadingScreen({
   const [errorMessage, setErrorMessage] = useState<string | null>(null)
   const [startTime] = useState(() => Date.now())
   const [elapsedSeconds, setElapsedSeconds] = useState(0)
+  const [startupFailure, setStartupFailure] = useState<StartupFailure | null>(null)
+  const [portCleanup, setPortCleanup] = useState<'idle' | 'running' | 'free' | 'busy'>('idle')
+  const [portCleanupError, setPortCleanupError] = useState<string | null>(null)
+  const [restoringConfig, setRestoringConfig] = useState(false)
+  const [restoreError, setRestoreError] = useState<string | null>(null)
+  // Set once the desktop shell reports a fatal startup error, so polling stops
+  const startupFailedRef = useRef(false)
+
+  // The desktop shell reports a backend that could not be started at all
+  useEffect(() => {
+    if ((window as any).__TAURI_INTERNALS__ === undefined) {
+      return
+    }
+    const unlisten = listen<StartupFailure>('backend://startup_failed', (event) => {
+      startupFailedRef.current = true
+      setStartupFailure(event.payload)
+      setStatus('startup_failed')
+    })
+    return () => {
+      unlisten.then((stop) => stop()).catch(() => {})
+    }
+  }, [])
 
   // Update elapsed time every second
   useEffect(() => {
@@ -90,8 +121,14 @@ function LoadingScreen({
           message: t('loading.error', 'Verbindungsfehler'),
           detail: errorMessage || t('loading.error_detail', 'Backend-Server nicht erreichbar')
         }
+      case 'startup_failed':
+        return {
+          status,
+          message: t('loading.startup_failed', 'Backend konnte nicht gestartet werden'),
+          detail: startupFailure?.message
+        }
     }
-  }, [status, attempt, errorMessage, t])
+  }, [status, attempt, errorMessage, startupFailure, t])
 
   const checkBackendHealth = useCallback(async (): Promise<boolean> => {
     try {
@@ -111,6 +148,9 @@ function LoadingScreen({
     await new Promise(resolve => setTimeout(resolve, 500))
     
     while (currentAttempt < maxRetries && !isReady) {
+      if (startupFailedRef.current) {
+        return
+      }
       setAttempt(currentAttempt)
       
       // Update status based on attempt number
@@ -146,6 +186,9 @@ function LoadingScreen({
       await new Promise(resolve => setTimeout(resolve, delay))
     }
     
+    if (startupFailedRef.current) {
+      return
+    }
     // Max retries exceeded
     setStatus('error')
     setErrorMessage(t('loading.max_retries', 'Backend-Server konnte nicht gestartet werden'))
@@ -162,12 +205,42 @@ function LoadingScreen({
     runHealthCheck()
   }
 
+  // Manual recovery for a port still held by an orphaned backend from an earlier run
+  const handleClearPort = async () => {
+    setPortCleanup('running')
+    setPortCleanupError(null)
+    try {
+      const free = await invoke<boolean>('clear_backend_port')
+      setPortCleanup(free ? 'free' : 'busy')
+    } catch (error) {
+      setPortCleanup('idle')
+      setPortCleanupError(String(error))
+    }
+  }
+
+  // Go back to the settings of the last startup that worked, then wait for the backend again
+  const handleRestoreConfig = async () => {
+    setRestoringConfig(true)
+    setRestoreError(null)
+    try {
+      await invoke('restore_last_good_config')
+      startupFailedRef.current = false
+      setStartupFailure(null)
+      handleRetry()
+    } catch (error) {
+      setRestoreError(String(error))
+    } finally {
+      setRestoringConfig(false)
+    }
+  }
+
   const statusInfo = getStatusInfo()
+  const isError = status === 'error' || status === 'startup_failed'
   
   // Calculate progress percentage (based on typical startup time of ~15-30 seconds)
   const progressPercent = status === 'ready' 
     ? 100 
-    : status === 'error' 
+    : isError
       ? 0 
       : Math.min(95, (elapsedSeconds / 30) * 100)
 
@@ -185,13 +258,13 @@ function LoadingScreen({
         {/* Logo/Icon */}
         <div className="text-center mb-8">
           <div className={`w-24 h-24 mx-auto mb-4 rounded-2xl flex items-center justify-center text-5xl shadow-lg ${
-            status === 'error'
+            isError
               ? 'bg-gradient-to-br from-red-500 to-orange-500'
               : status === 'ready'
                 ? 'bg-gradient-to-br from-green-500 to-emerald-500'
                 : 'bg-gradient-to-br from-blue-500 to-cyan-500'
-          } ${status !== 'error' && status !== 'ready' ? 'animate-pulse' : ''}`}>
-            {status === 'error' ? '⚠️' : status === 'ready' ? '✓' : '💰'}
+          } ${!isError && status !== 'ready' ? 'animate-pulse' : ''}`}>
+            {isError ? '⚠️' : status === 'ready' ? '✓' : '💰'}
           </div>
           <h1 className={`text-2xl font-bold mb-2 ${
             isDark ? 'text-white' : 'text-gray-900'
@@ -208,7 +281,7 @@ function LoadingScreen({
         {/* Status Message */}
         <div className="text-center mb-6">
           <p className={`text-lg font-semibold mb-1 ${
-            status === 'error'
+            isError
               ? 'text-red-500'
               : status === 'ready'
                 ? 'text-green-500'
@@ -226,7 +299,7 @@ function LoadingScreen({
         </div>
 
         {/* Progress Bar */}
-        {status !== 'error' && (
+        {!isError && (
           <div className="mb-6">
             <div className={`h-2 rounded-full overflow-hidden ${
               isDark ? 'bg-slate-700' : 'bg-gray-200'
@@ -256,7 +329,7 @@ function LoadingScreen({
         )}
 
         {/* Loading Animation */}
-        {status !== 'error' && status !== 'ready' && (
+        {!isError && status !== 'ready' && (
           <div className="flex justify-center mb-6">
             <div className="flex gap-1.5">
               {[0, 1, 2, 3, 4].map((i) => (
@@ -299,11 +372,83 @@ function LoadingScreen({
             >
               {t('loading.retry', 'Erneut versuchen')}
             </button>
+            {(window as any).__TAURI_INTERNALS__ !== undefined && (
+              <div className={`p-4 rounded-lg text-sm border ${
+                isDark 
+                  ? 'bg-slate-800/50 text-slate-300 border-slate-700/50' 
+                  : 'bg-gray-50 text-gray-700 border-gray-200'
+              }`}>
+                <p className="font-medium mb-2">
+                  {t('loading.troubleshooting_title', 'Fehlerbehebung')}
+                </p>
+                <button
+                  onClick={handleClearPort}
+                  disabled={portCleanup === 'running'}
+                  className={`w-full py-2 px-4 rounded-lg font-medium transition-all disabled:opacity-50 ${
+                    isDark 
+                      ? 'bg-slate-700 hover:bg-slate-600 text-white' 
+                      : 'bg-white hover:bg-gray-100 text-gray-800 border border-gray-300'
+                  }`}
+                >
+                  {portCleanup === 'running'
+                    ? t('loading.clear_port_running', 'Port wird freigegeben...')
+                    : t('loading.clear_port', 'Backend-Port freigeben')}
+                </button>
+                {portCleanup === 'free' && (
+                  <p className="text-xs mt-2 text-green-500">
+                    {t('loading.clear_port_free', 'Der Port ist frei, bitte erneut versuchen.')}
+                  </p>
+                )}
+                {portCleanup === 'busy' && (
+                  <p className="text-xs mt-2 text-red-500">
+                    {t('loading.clear_port_busy', 'Der Port ist weiterhin belegt.')}
+                  </p>
+                )}
+                {portCleanupError && (
+                  <p className="text-xs mt-2 text-red-500">{portCleanupError}</p>
+                )}
+              </div>
+            )}
+          </div>
+        )}
+
+        {/* Fatal startup error reported by the desktop shell; retrying the connection won't help */}
+        {status === 'startup_failed' && startupFailure && (
+          <div className={`p-4 rounded-lg text-sm ${
+            isDark 
+              ? 'bg-red-900/30 text-red-200 border border-red-800/50' 
+              : 'bg-red-50 text-red-700 border border-red-200'
+          }`}>
+            <p className="font-medium mb-2">
+              {t('loading.startup_failed_help', 'Bitte starten Sie die Anwendung neu. Details stehen im Anwendungsprotokoll.')}
+            </p>
+            <p className="text-xs font-mono">
+              {t('loading.error_code', 'Fehlercode')}: {startupFailure.code}
+            </p>
+            {startupFailure.known_good_available && (
+              <div className="mt-3">
+                <p className="text-xs mb-2">
+                  {t('loading.restore_config_help', 'Die Einstellungen des letzten erfolgreichen Starts können wiederhergestellt werden.')}
+                </p>
+                <button
+                  onClick={handleRestoreConfig}
+                  disabled={restoringConfig}
+                  className="w-full py-2 px-4 bg-blue-600 hover:bg-blue-700 text-white font-medium rounded-lg transition-all disabled:opacity-50"
+                >
+                  {restoringConfig
+                    ? t('loading.restore_config_running', 'Einstellungen werden wiederhergestellt...')
+                    : t('loading.restore_config', 'Letzte funktionierende Einstellungen wiederherstellen')}
+                </button>
+                {restoreError && (
+                  <p className="text-xs mt-2">{restoreError}</p>
+                )}
+              </div>
+            )}
           </div>
         )}
 
         {/* Info Text (during loading) */}
-        {status !== 'error' && status !== 'ready' && (
+        {!isError && status !== 'ready' && (
           <div className={`text-center text-xs ${
             isDark ? 'text-slate-500' : 'text-gray-400'
           }`}>
@@ -1,4 +1,6 @@
 import axios from 'axios'
+import { invoke } from '@tauri-apps/api/core'
+import { listen } from '@tauri-apps/api/event'
 import type {
   Budget,
   BudgetCategory,
@@ -66,6 +68,55 @@ const api = axios.create({
   timeout: 10000, // 10 second timeout
 })
 
+// The desktop shell may move the backend off port 8000 when the port is taken by
+// another application, so follow the port it reports instead of assuming the default
+if (typeof window !== 'undefined' && (window as any).__TAURI_INTERNALS__ !== undefined && !import.meta.env.VITE_API_URL) {
+  // The scheme follows the backend_tls setting and is looked up again with every port change
+  const useBackendPort = async (port: number) => {
+    const scheme = await invoke<string>('get_backend_scheme').catch(() => 'http')
+    api.defaults.baseURL = `${scheme}://localhost:${port}/api`
+    console.log('Backend port:', port, '- API URL:', api.defaults.baseURL)
+  }
+  invoke<number>('get_backend_port')
+    .then(useBackendPort)
+    .catch((error) => console.error('Could not query backend port:', error))
+  listen<number>('backend://port_changed', (event) => useBackendPort(event.payload))
+    .catch((error) => console.error('Could not listen for backend port changes:', error))
+
+  // A backend suspended for low power mode can't answer, so wake it before any request goes out
+  let backendSuspended = false
+  listen<{ suspended: boolean }>('backend://suspend_changed', (event) => {
+    backendSuspended = event.payload.suspended
+  }).catch((error) => console.error('Could not listen for backend suspend changes:', error))
+  api.interceptors.request.use(async (config) => {
+    if (backendSuspended) {
+      await invoke('resume_backend')
+    }
+    return config
+  })
+}
+
+export interface ConnectionReport {
+  backend_port: number
+  frontend_port: number | null
+  ports_match: boolean
+  healthy: boolean
+  latency_ms: number | null
+  error: string | null
+}
+
+// Connectivity self-test: asks the desktop shell whether the backend answers on the port used here
+// A relative base URL goes through the dev server proxy, so there is no port to compare
+export const verifyConnection = () => {
+  const baseURL = api.defaults.baseURL ?? ''
+  let port: number | null = null
+  if (/^https?:\/\//.test(baseURL)) {
+    const url = new URL(baseURL)
+    port = Number(url.port || (url.protocol === 'https:' ? 443 : 80))
+  }
+  return invoke<ConnectionReport>('verify_connection', { frontendPort: port })
+}
+
 // Log API configuration on startup
 if (typeof window !== 'undefined') {
   console.log('=== API Configuration ===')